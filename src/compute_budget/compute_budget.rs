@@ -1,4 +1,10 @@
-use crate::{token::constants::compute_budget_id, utils};
+use crate::metaplex::metadata_program_id;
+use crate::token::constants::{
+    associated_account_program_id, compute_budget_id, system_program_id, token22_program_id,
+    token_program_id,
+};
+use crate::token::program_error::ProgramError;
+use crate::utils;
 use ic_solana::types::Instruction;
 // use borsh::{BorshDeserialize, BorshSerialize};
 use anyhow::anyhow;
@@ -10,12 +16,64 @@ use std::{
     fmt::{Display, Formatter},
     str::FromStr,
 };
+use thiserror::Error;
 // const DEFAULT_SERIALIZER_CAPACITY: usize = 1024;
 
 // Temporary values--calculate this properly later.
 pub const DEFAULT_COMPUTE_UNITS: u64 = 200_000;
 pub const UPDATE_COMPUTE_UNITS: u32 = 50_000;
 
+// Estimated compute units consumed per instruction type.
+pub const COMPUTE_UNITS_COMPUTE_BUDGET: u32 = 150;
+pub const COMPUTE_UNITS_TRANSFER: u32 = 1_000;
+pub const COMPUTE_UNITS_MINT_TO: u32 = 2_400;
+pub const COMPUTE_UNITS_CREATE_ATA: u32 = 25_000;
+pub const COMPUTE_UNITS_CREATE_METADATA: u32 = 10_000;
+pub const COMPUTE_UNITS_UPDATE_METADATA: u32 = 8_000;
+/// Used for instructions of programs without a better estimate, matching
+/// the runtime's default per-instruction limit.
+pub const COMPUTE_UNITS_DEFAULT_INSTRUCTION: u32 = 200_000;
+
+/// SPL Token `MintTo` and `MintToChecked` instruction tags.
+const TOKEN_MINT_TO_TAGS: [u8; 2] = [7, 14];
+/// Token Metadata `Create` and `Update` instruction discriminators.
+const METADATA_CREATE_DISCRIMINATOR: u8 = 42;
+const METADATA_UPDATE_DISCRIMINATOR: u8 = 50;
+
+fn estimate_compute_units(instruction: &Instruction) -> u32 {
+    let program_id = instruction.program_id;
+    let tag = instruction.data.first().copied();
+    if program_id == compute_budget_id() {
+        COMPUTE_UNITS_COMPUTE_BUDGET
+    } else if program_id == system_program_id() {
+        COMPUTE_UNITS_TRANSFER
+    } else if program_id == token_program_id() || program_id == token22_program_id() {
+        match tag {
+            Some(tag) if TOKEN_MINT_TO_TAGS.contains(&tag) => COMPUTE_UNITS_MINT_TO,
+            _ => COMPUTE_UNITS_TRANSFER,
+        }
+    } else if program_id == associated_account_program_id() {
+        COMPUTE_UNITS_CREATE_ATA
+    } else if program_id == metadata_program_id() {
+        match tag {
+            Some(METADATA_CREATE_DISCRIMINATOR) => COMPUTE_UNITS_CREATE_METADATA,
+            Some(METADATA_UPDATE_DISCRIMINATOR) => COMPUTE_UNITS_UPDATE_METADATA,
+            _ => COMPUTE_UNITS_DEFAULT_INSTRUCTION,
+        }
+    } else {
+        COMPUTE_UNITS_DEFAULT_INSTRUCTION
+    }
+}
+
+/// Estimated compute units for a transaction made of `instructions`, based on
+/// the program each instruction targets.
+pub fn sum_compute_units(instructions: &[Instruction]) -> u32 {
+    instructions
+        .iter()
+        .map(estimate_compute_units)
+        .fold(0, u32::saturating_add)
+}
+
 // Temporary simple priority fees
 #[derive(CandidType, Deserialize, Serialize, Debug, Default, Clone, Eq, PartialEq)]
 pub enum Priority {
@@ -42,6 +100,46 @@ impl FromStr for Priority {
     }
 }
 
+impl Priority {
+    /// Compute unit price for this priority using the default fee schedule.
+    pub fn to_micro_lamports(&self) -> u64 {
+        self.to_micro_lamports_with(&PriorityFeeConfig::default())
+    }
+
+    /// Compute unit price for this priority using a custom fee schedule.
+    pub fn to_micro_lamports_with(&self, config: &PriorityFeeConfig) -> u64 {
+        match self {
+            Self::None => config.none,
+            Self::Low => config.low,
+            Self::Medium => config.medium,
+            Self::High => config.high,
+            Self::Max => config.max,
+        }
+    }
+}
+
+/// Compute unit price, in micro-lamports, for each `Priority` level.
+#[derive(CandidType, Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct PriorityFeeConfig {
+    pub none: u64,
+    pub low: u64,
+    pub medium: u64,
+    pub high: u64,
+    pub max: u64,
+}
+
+impl Default for PriorityFeeConfig {
+    fn default() -> Self {
+        Self {
+            none: 0,
+            low: 1,
+            medium: 5_000,
+            high: 50_000,
+            max: 1_000_000,
+        }
+    }
+}
+
 impl Display for Priority {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
@@ -81,9 +179,17 @@ pub enum ComputeBudgetInstruction {
 
 impl ComputeBudgetInstruction {
     /// Create a `ComputeBudgetInstruction::RequestHeapFrame` `Instruction`
-    pub fn request_heap_frame(bytes: u32) -> Instruction {
+    ///
+    /// Fails if `bytes` is not a multiple of 1024 or falls outside
+    /// `MIN_HEAP_FRAME_BYTES..=MAX_HEAP_FRAME_BYTES`.
+    pub fn request_heap_frame(bytes: u32) -> Result<Instruction, ComputeBudgetError> {
+        validate_heap_frame(bytes)?;
         // Instruction::new_with_borsh(compute_budget_id(), &Self::RequestHeapFrame(bytes), vec![])
-        utils::new_with_borsh(compute_budget_id(), &Self::RequestHeapFrame(bytes), vec![])
+        Ok(utils::new_with_borsh(
+            compute_budget_id(),
+            &Self::RequestHeapFrame(bytes),
+            vec![],
+        ))
     }
 
     /// Create a `ComputeBudgetInstruction::SetComputeUnitLimit` `Instruction`
@@ -125,6 +231,11 @@ impl ComputeBudgetInstruction {
         // Ok(result)
     }
 
+    /// Deserialize instruction data produced by [`ComputeBudgetInstruction::pack`]
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        borsh::from_slice(data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
     /// Create a `ComputeBudgetInstruction::SetLoadedAccountsDataSizeLimit` `Instruction`
     pub fn set_loaded_accounts_data_size_limit(bytes: u32) -> Instruction {
         // Instruction::new_with_borsh(
@@ -139,3 +250,94 @@ impl ComputeBudgetInstruction {
         )
     }
 }
+
+/// The `SetComputeUnitLimit` and `SetComputeUnitPrice` pair prepended to most transactions.
+pub fn compute_budget_instructions(cu_limit: u32, priority: Priority) -> [Instruction; 2] {
+    [
+        ComputeBudgetInstruction::set_compute_unit_limit(cu_limit),
+        ComputeBudgetInstruction::set_compute_unit_price(priority.to_micro_lamports()),
+    ]
+}
+
+/// Smallest heap frame that can be requested, in bytes.
+pub const MIN_HEAP_FRAME_BYTES: u32 = 32 * 1024;
+/// Largest heap frame that can be requested, in bytes.
+pub const MAX_HEAP_FRAME_BYTES: u32 = 256 * 1024;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ComputeBudgetError {
+    #[error("Heap frame size must be a multiple of 1024 between 32KiB and 256KiB, found {0}")]
+    InvalidHeapFrameSize(u32),
+}
+
+fn validate_heap_frame(bytes: u32) -> Result<(), ComputeBudgetError> {
+    if !bytes.is_multiple_of(1024)
+        || !(MIN_HEAP_FRAME_BYTES..=MAX_HEAP_FRAME_BYTES).contains(&bytes)
+    {
+        return Err(ComputeBudgetError::InvalidHeapFrameSize(bytes));
+    }
+    Ok(())
+}
+
+/// Instructions builder for the compute budget of a transaction.
+///
+/// Only the settings that are set produce an instruction.
+#[derive(Default)]
+pub struct ComputeBudgetBuilder {
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+    heap_frame: Option<u32>,
+    loaded_accounts_data_size: Option<u32>,
+}
+
+impl ComputeBudgetBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn compute_unit_limit(&mut self, units: u32) -> &mut Self {
+        self.compute_unit_limit = Some(units);
+        self
+    }
+    /// Compute unit price in micro-lamports
+    #[inline(always)]
+    pub fn compute_unit_price(&mut self, micro_lamports: u64) -> &mut Self {
+        self.compute_unit_price = Some(micro_lamports);
+        self
+    }
+    /// Sets the compute unit price from the default fee schedule of `priority`
+    #[inline(always)]
+    pub fn priority(&mut self, priority: Priority) -> &mut Self {
+        self.compute_unit_price = Some(priority.to_micro_lamports());
+        self
+    }
+    /// Heap frame size in bytes, must be a multiple of 1024
+    #[inline(always)]
+    pub fn heap_frame(&mut self, bytes: u32) -> &mut Self {
+        self.heap_frame = Some(bytes);
+        self
+    }
+    #[inline(always)]
+    pub fn loaded_accounts_data_size(&mut self, bytes: u32) -> &mut Self {
+        self.loaded_accounts_data_size = Some(bytes);
+        self
+    }
+    pub fn build(&self) -> Result<Vec<Instruction>, ComputeBudgetError> {
+        let mut instructions = vec![];
+        if let Some(units) = self.compute_unit_limit {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+        }
+        if let Some(micro_lamports) = self.compute_unit_price {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+                micro_lamports,
+            ));
+        }
+        if let Some(bytes) = self.heap_frame {
+            instructions.push(ComputeBudgetInstruction::request_heap_frame(bytes)?);
+        }
+        if let Some(bytes) = self.loaded_accounts_data_size {
+            instructions.push(ComputeBudgetInstruction::set_loaded_accounts_data_size_limit(bytes));
+        }
+        Ok(instructions)
+    }
+}
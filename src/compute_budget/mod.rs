@@ -1 +1 @@
-pub mod compute_budget;
\ No newline at end of file
+pub mod compute_budget;
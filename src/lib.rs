@@ -1,5 +1,35 @@
+pub mod alt;
+pub mod bpf_loader;
 pub mod compute_budget;
+pub mod memo;
 pub mod metaplex;
 // pub mod pubkey;
+pub mod stake;
 pub mod token;
 pub mod utils;
+
+use metaplex::types::{BuilderError, CreatorError, MetaplexError};
+use thiserror::Error;
+use token::program_error::ProgramError;
+use token::system_instruction::SystemError;
+
+/// Unifies the error types of the crate so canisters can propagate them with `?`.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum IcSplError {
+    #[error(transparent)]
+    ProgramError(#[from] ProgramError),
+    #[error(transparent)]
+    SystemError(#[from] SystemError),
+    #[error(transparent)]
+    BuilderError(#[from] BuilderError),
+    #[error(transparent)]
+    MetaplexError(#[from] MetaplexError),
+    #[error(transparent)]
+    CreatorError(#[from] CreatorError),
+    #[error("Invalid pubkey: {0}")]
+    InvalidPubkey(String),
+    #[error("Invalid extension order: {0}")]
+    InvalidExtensionOrder(String),
+    #[error("Conflicting extensions: {0}")]
+    ConflictingExtensions(String),
+}
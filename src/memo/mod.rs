@@ -0,0 +1,24 @@
+use crate::token::constants::memo_program_id;
+use ic_solana::types::{AccountMeta, Instruction, Pubkey};
+
+/// Longest memo that still fits in a transaction alongside a single signer.
+pub const MAX_MEMO_LENGTH: usize = 566;
+
+/// Build a memo instruction, possibly signed
+///
+/// Accounts expected by this instruction:
+///
+///   0. `..0+N` `[signer]` Expected signers; if zero provided, instruction will
+///      be processed as a normal, unsigned spl-memo
+///
+/// The memo is passed as the raw UTF-8 instruction data, without a length prefix.
+pub fn build_memo(memo: &str, signer_pubkeys: &[&Pubkey]) -> Instruction {
+    Instruction {
+        program_id: memo_program_id(),
+        accounts: signer_pubkeys
+            .iter()
+            .map(|&pubkey| AccountMeta::new_readonly(*pubkey, true))
+            .collect(),
+        data: memo.as_bytes().to_vec(),
+    }
+}
@@ -1,11 +1,23 @@
+use crate::metaplex::extension;
+use crate::metaplex::extension::calculate_mint_account_size;
 use crate::metaplex::extension::ExtensionType;
+use crate::metaplex::types::BuilderError;
 
 use crate::token::constants::token22_program_id;
+use crate::token::program_error::ProgramError;
+use crate::IcSplError;
 
 use crate::token::system_instruction::create_account;
+use crate::token::system_instruction::minimum_balance_for_rent_exemption;
+use crate::token::system_instruction::minimum_balance_for_rent_exemption_with_rate;
+use crate::token::token_instruction::initialize_interest_bearing_mint;
 use crate::token::token_instruction::initialize_metadata_pointer;
 use crate::token::token_instruction::initialize_mint2;
 use crate::token::token_instruction::initialize_mint_close_authority;
+use crate::token::token_instruction::initialize_non_transferable_mint;
+use crate::token::token_instruction::initialize_permanent_delegate;
+use crate::token::token_instruction::initialize_transfer_fee_config;
+use crate::token::token_instruction::initialize_transfer_hook;
 use crate::token::token_metadata::initialize as initialize_metadata;
 use crate::token::token_metadata::update_field as add_additional_metadata;
 use crate::token::token_metadata::Field;
@@ -15,10 +27,10 @@ use ic_solana::types::Pubkey;
 
 use serde_derive::Deserialize;
 use std::str::FromStr;
+
 pub struct CreateFungible22Args {
     pub mint: Pubkey,
     pub extensions: Fungible22Fields,
-    pub mint_size: u64,
     pub mint_rent: u64,
     pub decimals: u8,
     pub payer: Pubkey,
@@ -48,6 +60,7 @@ pub struct TransferHookConfig {
 }
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "candid", derive(candid::CandidType))]
 pub struct MetadataConfig {
     pub name: String,
     pub symbol: String,
@@ -66,16 +79,261 @@ pub struct Fungible22Fields {
     pub transfer_hook: Option<TransferHookConfig>,
 }
 
-pub fn create_fungible_22_ix(args: CreateFungible22Args) -> Vec<Instruction> {
-    let is_close_authority = args.extensions.close_authority.is_some();
+impl Fungible22Fields {
+    /// Rejects extension combinations the Token-2022 program refuses to
+    /// initialize together.
+    pub fn validate(&self) -> Result<(), IcSplError> {
+        if self.non_transferrable == Some(true) {
+            if self.transfer_fee.is_some() {
+                return Err(IcSplError::ConflictingExtensions(
+                    "non_transferrable conflicts with transfer_fee".to_string(),
+                ));
+            }
+            if self.transfer_hook.is_some() {
+                return Err(IcSplError::ConflictingExtensions(
+                    "non_transferrable conflicts with transfer_hook".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Extensions in the order `create_fungible_22_ix` initializes them, ending
+    /// with `TokenMetadata` when metadata is set.
+    pub fn extension_types(&self) -> Vec<ExtensionType> {
+        let mut extension_types = vec![];
+        if self.metadata.is_some() {
+            extension_types.push(ExtensionType::MetadataPointer);
+        }
+        if self.close_authority.is_some() {
+            extension_types.push(ExtensionType::MintCloseAuthority);
+        }
+        if self.permanent_delegate.is_some() {
+            extension_types.push(ExtensionType::PermanentDelegate);
+        }
+        if self.non_transferrable == Some(true) {
+            extension_types.push(ExtensionType::NonTransferable);
+        }
+        if self.transfer_fee.is_some() {
+            extension_types.push(ExtensionType::TransferFeeConfig);
+        }
+        if self.interest_bearing.is_some() {
+            extension_types.push(ExtensionType::InterestBearingConfig);
+        }
+        if self.transfer_hook.is_some() {
+            extension_types.push(ExtensionType::TransferHook);
+        }
+        if self.metadata.is_some() {
+            extension_types.push(ExtensionType::TokenMetadata);
+        }
+        extension_types
+    }
+}
+
+/// Instruction args builder for `create_fungible_22_ix`.
+///
+/// `mint`, `mint_rent`, `decimals` and `payer` are required,
+/// every extension is optional.
+#[derive(Default)]
+pub struct CreateFungible22ArgsBuilder {
+    mint: Option<Pubkey>,
+    mint_rent: Option<u64>,
+    decimals: Option<u8>,
+    payer: Option<Pubkey>,
+    metadata: Option<MetadataConfig>,
+    close_authority: Option<String>,
+    permanent_delegate: Option<String>,
+    non_transferrable: Option<bool>,
+    transfer_fee: Option<TransferFeeConfig>,
+    interest_bearing: Option<InterestBearingConfig>,
+    transfer_hook: Option<TransferHookConfig>,
+}
+
+impl CreateFungible22ArgsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        *self = Self::default();
+        self
+    }
+    /// Mint account to create
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Rent exempt lamports for the mint account
+    #[inline(always)]
+    pub fn mint_rent(&mut self, mint_rent: u64) -> &mut Self {
+        self.mint_rent = Some(mint_rent);
+        self
+    }
+    #[inline(always)]
+    pub fn decimals(&mut self, decimals: u8) -> &mut Self {
+        self.decimals = Some(decimals);
+        self
+    }
+    /// Payer and mint authority
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: MetadataConfig) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    #[inline(always)]
+    pub fn close_authority(&mut self, close_authority: String) -> &mut Self {
+        self.close_authority = Some(close_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn permanent_delegate(&mut self, permanent_delegate: String) -> &mut Self {
+        self.permanent_delegate = Some(permanent_delegate);
+        self
+    }
+    #[inline(always)]
+    pub fn non_transferrable(&mut self, non_transferrable: bool) -> &mut Self {
+        self.non_transferrable = Some(non_transferrable);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_fee(&mut self, transfer_fee: TransferFeeConfig) -> &mut Self {
+        self.transfer_fee = Some(transfer_fee);
+        self
+    }
+    #[inline(always)]
+    pub fn interest_bearing(&mut self, interest_bearing: InterestBearingConfig) -> &mut Self {
+        self.interest_bearing = Some(interest_bearing);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_hook(&mut self, transfer_hook: TransferHookConfig) -> &mut Self {
+        self.transfer_hook = Some(transfer_hook);
+        self
+    }
+    /// Checks that all required fields are set without building the args.
+    pub fn validate(&self) -> Result<(), BuilderError> {
+        if self.mint.is_none() {
+            return Err(BuilderError::MissingField("mint"));
+        }
+        if self.mint_rent.is_none() {
+            return Err(BuilderError::MissingField("mint_rent"));
+        }
+        if self.decimals.is_none() {
+            return Err(BuilderError::MissingField("decimals"));
+        }
+        if self.payer.is_none() {
+            return Err(BuilderError::MissingField("payer"));
+        }
+        Ok(())
+    }
+    pub fn build(&self) -> Result<CreateFungible22Args, BuilderError> {
+        Ok(CreateFungible22Args {
+            mint: self.mint.ok_or(BuilderError::MissingField("mint"))?,
+            extensions: Fungible22Fields {
+                metadata: self.metadata.clone(),
+                close_authority: self.close_authority.clone(),
+                permanent_delegate: self.permanent_delegate.clone(),
+                non_transferrable: self.non_transferrable,
+                transfer_fee: self.transfer_fee.clone(),
+                interest_bearing: self.interest_bearing.clone(),
+                transfer_hook: self.transfer_hook.clone(),
+            },
+            mint_rent: self
+                .mint_rent
+                .ok_or(BuilderError::MissingField("mint_rent"))?,
+            decimals: self
+                .decimals
+                .ok_or(BuilderError::MissingField("decimals"))?,
+            payer: self.payer.ok_or(BuilderError::MissingField("payer"))?,
+        })
+    }
+}
+
+/// Space the token metadata extension is reallocated with once initialized.
+fn token_metadata_space(metadata: &MetadataConfig) -> u64 {
+    extension::token_metadata_space(
+        metadata.name.len(),
+        metadata.symbol.len(),
+        metadata.uri.len(),
+        metadata
+            .additional_metadata
+            .iter()
+            .flatten()
+            .map(|[key, value]| (key.len(), value.len())),
+    )
+}
+
+/// Checks that `extensions` are listed in the order their instructions must be
+/// emitted. Extensions are initialized before `initialize_mint2`, except for
+/// `TokenMetadata`, `TokenGroup` and `TokenGroupMember`, which need an
+/// initialized mint and their pointer extension listed before them.
+pub fn validate_extension_order(extensions: &[ExtensionType]) -> Result<(), IcSplError> {
+    let mut mint_initialized = false;
+    for (index, extension) in extensions.iter().enumerate() {
+        let pointer = match extension {
+            ExtensionType::TokenMetadata => Some(ExtensionType::MetadataPointer),
+            ExtensionType::TokenGroup => Some(ExtensionType::GroupPointer),
+            ExtensionType::TokenGroupMember => Some(ExtensionType::GroupMemberPointer),
+            _ => None,
+        };
+        match pointer {
+            Some(pointer) => {
+                if !extensions[..index].contains(&pointer) {
+                    return Err(IcSplError::InvalidExtensionOrder(format!(
+                        "{} must come after {}",
+                        extension, pointer
+                    )));
+                }
+                mint_initialized = true;
+            }
+            None if mint_initialized => {
+                return Err(IcSplError::InvalidExtensionOrder(format!(
+                    "{} must be initialized before the mint",
+                    extension
+                )));
+            }
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+fn parse_pubkey(pubkey: &str) -> Result<Pubkey, IcSplError> {
+    Pubkey::from_str(pubkey).map_err(|_| IcSplError::InvalidPubkey(pubkey.to_string()))
+}
+
+pub fn create_fungible_22_ix(args: CreateFungible22Args) -> Result<Vec<Instruction>, IcSplError> {
+    args.extensions.validate()?;
+
+    let extension_types = args.extensions.extension_types();
+    validate_extension_order(&extension_types)?;
 
     let is_metadata = args.extensions.metadata.is_some();
 
-    let mut extension_types = vec![];
+    let is_non_transferrable = args.extensions.non_transferrable == Some(true);
 
-    // Adding extensions
-    if is_close_authority {
-        extension_types.push(ExtensionType::MintCloseAuthority);
+    // The metadata is not part of the created account, the program
+    // reallocates the mint when it is initialized
+    let mint_extension_types: Vec<ExtensionType> = extension_types
+        .into_iter()
+        .filter(|extension| *extension != ExtensionType::TokenMetadata)
+        .collect();
+
+    let mint_space = calculate_mint_account_size(&mint_extension_types);
+    let metadata_space = args
+        .extensions
+        .metadata
+        .as_ref()
+        .map(token_metadata_space)
+        .unwrap_or_default();
+    if args.mint_rent < minimum_balance_for_rent_exemption(mint_space + metadata_space)? {
+        return Err(ProgramError::AccountNotRentExempt.into());
     }
 
     let mut instructions = vec![];
@@ -84,7 +342,7 @@ pub fn create_fungible_22_ix(args: CreateFungible22Args) -> Vec<Instruction> {
         &args.payer,
         &args.mint,
         args.mint_rent,
-        args.mint_size,
+        mint_space,
         &token22_program_id(),
     );
     instructions.push(create_mint_account_ix);
@@ -97,12 +355,72 @@ pub fn create_fungible_22_ix(args: CreateFungible22Args) -> Vec<Instruction> {
     }
 
     if let Some(close_authority) = args.extensions.close_authority {
-        let close_authority = Pubkey::from_str(&close_authority).unwrap();
+        let close_authority = parse_pubkey(&close_authority)?;
         let init_close_authority_ix =
             initialize_mint_close_authority(&args.mint, Some(&close_authority));
         instructions.push(init_close_authority_ix);
     }
 
+    if let Some(permanent_delegate) = args.extensions.permanent_delegate {
+        let permanent_delegate = parse_pubkey(&permanent_delegate)?;
+        let init_permanent_delegate_ix =
+            initialize_permanent_delegate(&args.mint, &permanent_delegate);
+        instructions.push(init_permanent_delegate_ix);
+    }
+
+    if is_non_transferrable {
+        let init_non_transferable_ix = initialize_non_transferable_mint(&args.mint);
+        instructions.push(init_non_transferable_ix);
+    }
+
+    if let Some(TransferFeeConfig {
+        transfer_fee_config_authority,
+        withdraw_withheld_authority,
+        fee_basis_points,
+        max_fee,
+    }) = args.extensions.transfer_fee
+    {
+        let transfer_fee_config_authority = transfer_fee_config_authority
+            .as_deref()
+            .map(parse_pubkey)
+            .transpose()?;
+        let withdraw_withheld_authority = withdraw_withheld_authority
+            .as_deref()
+            .map(parse_pubkey)
+            .transpose()?;
+        let init_transfer_fee_ix = initialize_transfer_fee_config(
+            &args.mint,
+            transfer_fee_config_authority.as_ref(),
+            withdraw_withheld_authority.as_ref(),
+            fee_basis_points,
+            max_fee,
+        );
+        instructions.push(init_transfer_fee_ix);
+    }
+
+    if let Some(InterestBearingConfig {
+        rate_authority,
+        rate,
+    }) = args.extensions.interest_bearing
+    {
+        let rate_authority = rate_authority.as_deref().map(parse_pubkey).transpose()?;
+        let init_interest_bearing_ix =
+            initialize_interest_bearing_mint(&args.mint, rate_authority.as_ref(), rate);
+        instructions.push(init_interest_bearing_ix);
+    }
+
+    if let Some(TransferHookConfig {
+        program_id,
+        authority,
+    }) = args.extensions.transfer_hook
+    {
+        let program_id = program_id.as_deref().map(parse_pubkey).transpose()?;
+        let authority = authority.as_deref().map(parse_pubkey).transpose()?;
+        let init_transfer_hook_ix =
+            initialize_transfer_hook(&args.mint, authority.as_ref(), program_id.as_ref());
+        instructions.push(init_transfer_hook_ix);
+    }
+
     // Initialize mint
     let initialize_mint_ix = initialize_mint2(
         &token22_program_id(),
@@ -147,5 +465,105 @@ pub fn create_fungible_22_ix(args: CreateFungible22Args) -> Vec<Instruction> {
         }
     }
 
-    instructions
+    Ok(instructions)
+}
+
+/// Instructions creating a Token-2022 mint that stores its own metadata,
+/// with `payer` as mint, metadata pointer and update authority.
+///
+/// The mint account is funded for the metadata it will hold once
+/// initialized, at `lamports_per_byte_year` with the default exemption
+/// threshold.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_token22_with_metadata(
+    payer: &Pubkey,
+    mint: &Pubkey,
+    decimals: u8,
+    name: String,
+    symbol: String,
+    uri: String,
+    close_authority: Option<Pubkey>,
+    lamports_per_byte_year: u64,
+) -> Result<Vec<Instruction>, IcSplError> {
+    let mut extension_types = vec![ExtensionType::MetadataPointer];
+    if close_authority.is_some() {
+        extension_types.push(ExtensionType::MintCloseAuthority);
+    }
+
+    let mint_space = calculate_mint_account_size(&extension_types);
+    let metadata_space = token_metadata_space(&MetadataConfig {
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: None,
+    });
+    let lamports = minimum_balance_for_rent_exemption_with_rate(
+        mint_space + metadata_space,
+        lamports_per_byte_year,
+    )?;
+
+    let mut instructions = vec![
+        create_account(payer, mint, lamports, mint_space, &token22_program_id()),
+        initialize_metadata_pointer(mint, mint, payer),
+    ];
+    if let Some(close_authority) = close_authority {
+        instructions.push(initialize_mint_close_authority(
+            mint,
+            Some(&close_authority),
+        ));
+    }
+    instructions.push(initialize_mint2(
+        &token22_program_id(),
+        mint,
+        payer,
+        Some(payer),
+        decimals,
+    ));
+    instructions.push(initialize_metadata(
+        &token22_program_id(),
+        mint,
+        payer,
+        mint,
+        payer,
+        name,
+        symbol,
+        uri,
+    ));
+
+    Ok(instructions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extension_order_accepts_pointer_before_metadata() {
+        assert!(validate_extension_order(&[
+            ExtensionType::MetadataPointer,
+            ExtensionType::TransferFeeConfig,
+            ExtensionType::TokenMetadata,
+        ])
+        .is_ok());
+    }
+
+    #[test]
+    fn extension_order_rejects_metadata_without_pointer() {
+        assert!(matches!(
+            validate_extension_order(&[ExtensionType::TokenMetadata]),
+            Err(IcSplError::InvalidExtensionOrder(_))
+        ));
+    }
+
+    #[test]
+    fn extension_order_rejects_pre_mint_extension_after_metadata() {
+        assert!(matches!(
+            validate_extension_order(&[
+                ExtensionType::MetadataPointer,
+                ExtensionType::TokenMetadata,
+                ExtensionType::MintCloseAuthority,
+            ]),
+            Err(IcSplError::InvalidExtensionOrder(_))
+        ));
+    }
 }
@@ -1,7 +1,10 @@
 use crate::metaplex::derive_metadata_pda;
 use crate::metaplex::types::CreateArgs;
+use crate::metaplex::types::Creator;
 
+use crate::metaplex::types::DataV2;
 use crate::metaplex::types::FungibleFields;
+use crate::metaplex::types::MetaplexError;
 use crate::metaplex::types::TokenStandard;
 use crate::metaplex::CreateBuilder;
 use crate::token::constants::token_program_id;
@@ -15,20 +18,27 @@ pub struct CreateFungibleArgs {
     pub decimals: u8,
     pub immutable: bool,
     pub payer: Pubkey,
+    /// Defaults to `0`.
+    pub seller_fee_basis_points: Option<u16>,
+    pub creators: Option<Vec<Creator>>,
     // pub initial_supply: Option<f64>,
     // pub priority: Priority,
     // pub full_compute: bool,
 }
 
-pub fn create_fungible_ix(args: CreateFungibleArgs) -> Instruction {
+pub fn create_fungible_ix(args: CreateFungibleArgs) -> Result<Instruction, MetaplexError> {
     let metadata_pubkey = derive_metadata_pda(&args.mint);
 
+    let mut data_v2: DataV2 = args.metadata.into();
+    data_v2.seller_fee_basis_points = args.seller_fee_basis_points.unwrap_or(0);
+    data_v2.creators = args.creators;
+
     let create_args = CreateArgs::V1 {
-        name: args.metadata.name,
-        symbol: args.metadata.symbol,
-        uri: args.metadata.uri,
-        seller_fee_basis_points: 0,
-        creators: None,
+        name: data_v2.name,
+        symbol: data_v2.symbol,
+        uri: data_v2.uri,
+        seller_fee_basis_points: data_v2.seller_fee_basis_points,
+        creators: data_v2.creators,
         primary_sale_happened: false,
         is_mutable: !args.immutable,
         token_standard: TokenStandard::Fungible,
@@ -39,6 +49,7 @@ pub fn create_fungible_ix(args: CreateFungibleArgs) -> Instruction {
         rule_set: None,
         print_supply: None,
     };
+    create_args.validate()?;
 
     let create_ix = CreateBuilder::new()
         .metadata(metadata_pubkey)
@@ -48,7 +59,7 @@ pub fn create_fungible_ix(args: CreateFungibleArgs) -> Instruction {
         .update_authority(args.payer, true)
         .create_args(create_args)
         .spl_token_program(Some(token_program_id()))
-        .instruction();
+        .instruction()?;
 
-    create_ix
+    Ok(create_ix)
 }
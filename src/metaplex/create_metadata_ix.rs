@@ -1,10 +1,14 @@
+use crate::metaplex::derive_edition_pda;
 use crate::metaplex::derive_metadata_pda;
+use crate::metaplex::types::BuilderError;
 use crate::metaplex::types::CreateArgs;
 use crate::metaplex::types::DataV2;
 use crate::metaplex::types::FungibleFields;
+use crate::metaplex::types::MetaplexError;
+use crate::metaplex::types::PrintSupply;
 use crate::metaplex::types::TokenStandard;
 use crate::metaplex::CreateBuilder;
-use crate::token::program_error::ProgramError;
+use crate::token::constants::token_program_id;
 use ic_solana::types::Instruction;
 use ic_solana::types::Pubkey;
 use std::str::FromStr;
@@ -16,13 +20,110 @@ pub struct CreateMetadataArgs {
     pub metadata: FungibleFields,
     pub immutable: bool,
     pub payer: Pubkey,
+    /// Defaults to `Fungible`
+    pub token_standard: Option<TokenStandard>,
+    /// Overrides the derived edition pda for `NonFungible` and
+    /// `ProgrammableNonFungible` assets.
+    pub master_edition: Option<Pubkey>,
     // pub priority: Priority,
     // pub full_compute: bool,
 }
 
-pub fn create_metadata_ix(args: CreateMetadataArgs) -> Result<Instruction, ProgramError> {
-    let mint_pubkey = Pubkey::from_str(&args.mint).unwrap();
+/// Instruction args builder for `create_metadata_ix`.
+///
+/// `immutable` defaults to `false` and `token_standard` to `Fungible`.
+#[derive(Default)]
+pub struct CreateMetadataArgsBuilder {
+    mint: Option<String>,
+    metadata: Option<FungibleFields>,
+    immutable: bool,
+    payer: Option<Pubkey>,
+    token_standard: Option<TokenStandard>,
+    master_edition: Option<Pubkey>,
+}
+
+impl CreateMetadataArgsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        *self = Self::default();
+        self
+    }
+    /// Mint of token asset
+    #[inline(always)]
+    pub fn mint(&mut self, mint: String) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: FungibleFields) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    #[inline(always)]
+    pub fn immutable(&mut self, immutable: bool) -> &mut Self {
+        self.immutable = immutable;
+        self
+    }
+    /// Payer, also used as mint and update authority
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    #[inline(always)]
+    pub fn token_standard(&mut self, token_standard: TokenStandard) -> &mut Self {
+        self.token_standard = Some(token_standard);
+        self
+    }
+    /// `[optional account]`
+    /// Master edition, derived from the mint when not set
+    #[inline(always)]
+    pub fn master_edition(&mut self, master_edition: Option<Pubkey>) -> &mut Self {
+        self.master_edition = master_edition;
+        self
+    }
+    pub fn build(&self) -> Result<CreateMetadataArgs, BuilderError> {
+        Ok(CreateMetadataArgs {
+            mint: self
+                .mint
+                .clone()
+                .ok_or(BuilderError::MissingField("mint"))?,
+            metadata: self
+                .metadata
+                .clone()
+                .ok_or(BuilderError::MissingField("metadata"))?,
+            immutable: self.immutable,
+            payer: self.payer.ok_or(BuilderError::MissingField("payer"))?,
+            token_standard: self.token_standard.clone(),
+            master_edition: self.master_edition,
+        })
+    }
+    pub fn instruction(&self) -> Result<Instruction, MetaplexError> {
+        create_metadata_ix(self.build()?)
+    }
+}
+
+pub fn create_metadata_ix(args: CreateMetadataArgs) -> Result<Instruction, MetaplexError> {
+    let mint_pubkey = Pubkey::from_str(&args.mint)
+        .map_err(|_| MetaplexError::InvalidPubkeyString(args.mint.clone()))?;
     let metadata_pubkey = derive_metadata_pda(&mint_pubkey);
+    let token_standard = args.token_standard.unwrap_or(TokenStandard::Fungible);
+    let (master_edition, print_supply, spl_token_program) =
+        if token_standard.requires_master_edition() {
+            let edition = args
+                .master_edition
+                .unwrap_or_else(|| derive_edition_pda(&mint_pubkey));
+            (
+                Some(edition),
+                Some(PrintSupply::Zero),
+                Some(token_program_id()),
+            )
+        } else {
+            (None, None, None)
+        };
 
     let data_v2 = DataV2 {
         name: args.metadata.name,
@@ -42,23 +143,26 @@ pub fn create_metadata_ix(args: CreateMetadataArgs) -> Result<Instruction, Progr
         creators: data_v2.creators,
         primary_sale_happened: false,
         is_mutable: !args.immutable,
-        token_standard: TokenStandard::Fungible,
+        token_standard,
         collection: None,
         uses: None,
         collection_details: None,
         decimals: None,
         rule_set: None,
-        print_supply: None,
+        print_supply,
     };
+    create_args.validate()?;
 
     let create_ix = CreateBuilder::new()
         .metadata(metadata_pubkey)
+        .master_edition(master_edition)
         .mint(mint_pubkey, false)
         .authority(args.payer)
         .payer(args.payer)
         .update_authority(args.payer, true)
+        .spl_token_program(spl_token_program)
         .create_args(create_args)
-        .instruction();
+        .instruction()?;
 
     // let mut instructions = vec![];
 
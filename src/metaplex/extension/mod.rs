@@ -0,0 +1,224 @@
+use crate::token::program_error::ProgramError;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Size of a mint account without extensions.
+pub(crate) const MINT_LEN: u64 = 82;
+/// Mints with extensions are padded to the size of a token account, which is
+/// also the index of the account type byte.
+pub(crate) const ACCOUNT_LEN: u64 = 165;
+pub(crate) const ACCOUNT_TYPE_LEN: u64 = 1;
+/// Type and length prefix of every extension entry.
+pub const TLV_HEADER_LEN: u64 = 4;
+/// Accounts of exactly this size would be mistaken for a multisig.
+const MULTISIG_LEN: u64 = 355;
+/// Padding appended to a mint that would otherwise be a multisig's size.
+const EXTENSION_TYPE_LEN: u64 = std::mem::size_of::<ExtensionType>() as u64;
+
+/// Extensions that can be applied to mints or accounts.  Mint extensions must
+/// only be applied to mint accounts, and account extensions must only be
+/// applied to token holding accounts.
+#[repr(u16)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, TryFromPrimitive, IntoPrimitive, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ExtensionType {
+    /// Used as padding if the account size would otherwise be 355, same as a
+    /// multisig
+    Uninitialized,
+    /// Includes transfer fee rate info and accompanying authorities to withdraw
+    /// and set the fee
+    TransferFeeConfig,
+    /// Includes withheld transfer fees
+    TransferFeeAmount,
+    /// Includes an optional mint close authority
+    MintCloseAuthority,
+    /// Auditor configuration for confidential transfers
+    ConfidentialTransferMint,
+    /// State for confidential transfers
+    ConfidentialTransferAccount,
+    /// Specifies the default Account::state for new Accounts
+    DefaultAccountState,
+    /// Indicates that the Account owner authority cannot be changed
+    ImmutableOwner,
+    /// Require inbound transfers to have memo
+    MemoTransfer,
+    /// Indicates that the tokens from this mint can't be transferred
+    NonTransferable,
+    /// Tokens accrue interest over time,
+    InterestBearingConfig,
+    /// Locks privileged token operations from happening via CPI
+    CpiGuard,
+    /// Includes an optional permanent delegate
+    PermanentDelegate,
+    /// Indicates that the tokens in this account belong to a non-transferable
+    /// mint
+    NonTransferableAccount,
+    /// Mint requires a CPI to a program implementing the "transfer hook"
+    /// interface
+    TransferHook,
+    /// Indicates that the tokens in this account belong to a mint with a
+    /// transfer hook
+    TransferHookAccount,
+    /// Includes encrypted withheld fees and the encryption public that they are
+    /// encrypted under
+    ConfidentialTransferFeeConfig,
+    /// Includes confidential withheld transfer fees
+    ConfidentialTransferFeeAmount,
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds metadata
+    MetadataPointer,
+    /// Mint contains token-metadata
+    TokenMetadata,
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds group configurations
+    GroupPointer,
+    /// Mint contains token group configurations
+    TokenGroup,
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds group member configurations
+    GroupMemberPointer,
+    /// Mint contains token group member configurations
+    TokenGroupMember,
+    /// Test variable-length mint extension
+    #[cfg(test)]
+    VariableLenMintTest = u16::MAX - 2,
+    /// Padding extension used to make an account exactly Multisig::LEN, used
+    /// for testing
+    #[cfg(test)]
+    AccountPaddingTest,
+    /// Padding extension used to make a mint exactly Multisig::LEN, used for
+    /// testing
+    #[cfg(test)]
+    MintPaddingTest,
+}
+impl TryFrom<&[u8]> for ExtensionType {
+    type Error = ProgramError;
+    fn try_from(a: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from(u16::from_le_bytes(
+            a.try_into().map_err(|_| ProgramError::InvalidAccountData)?,
+        ))
+        .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+impl ExtensionType {
+    /// Length of the extension data, excluding its TLV header. Variable-length
+    /// extensions such as `TokenMetadata` report `0`.
+    pub fn data_len(&self) -> u64 {
+        match self {
+            Self::Uninitialized
+            | Self::ImmutableOwner
+            | Self::NonTransferable
+            | Self::NonTransferableAccount
+            | Self::TokenMetadata => 0,
+            Self::DefaultAccountState
+            | Self::MemoTransfer
+            | Self::CpiGuard
+            | Self::TransferHookAccount => 1,
+            Self::TransferFeeAmount => 8,
+            Self::MintCloseAuthority | Self::PermanentDelegate => 32,
+            Self::InterestBearingConfig => 52,
+            Self::TransferHook
+            | Self::MetadataPointer
+            | Self::GroupPointer
+            | Self::GroupMemberPointer
+            | Self::ConfidentialTransferFeeAmount => 64,
+            Self::ConfidentialTransferMint => 65,
+            Self::TokenGroupMember => 72,
+            Self::TokenGroup => 80,
+            Self::TransferFeeConfig => 108,
+            Self::ConfidentialTransferFeeConfig => 129,
+            Self::ConfidentialTransferAccount => 295,
+            #[cfg(test)]
+            Self::VariableLenMintTest | Self::AccountPaddingTest | Self::MintPaddingTest => 0,
+        }
+    }
+}
+
+/// Size of a Token-2022 mint account holding the given extensions.
+///
+/// A mint with extensions is padded to the size of a token account, followed
+/// by the account type byte and a TLV entry per distinct extension.
+pub fn calculate_mint_account_size(extensions: &[ExtensionType]) -> u64 {
+    if extensions.is_empty() {
+        return MINT_LEN;
+    }
+    let mut unique_extensions = Vec::with_capacity(extensions.len());
+    for extension in extensions {
+        if !unique_extensions.contains(extension) {
+            unique_extensions.push(*extension);
+        }
+    }
+    let tlv_len: u64 = unique_extensions
+        .iter()
+        .map(|extension| TLV_HEADER_LEN + extension.data_len())
+        .sum();
+    let size = ACCOUNT_LEN + ACCOUNT_TYPE_LEN + tlv_len;
+    if size == MULTISIG_LEN {
+        size + EXTENSION_TYPE_LEN
+    } else {
+        size
+    }
+}
+
+/// Space the `TokenMetadata` extension takes once initialized, TLV header
+/// included, for the given field lengths and `(key, value)` lengths of the
+/// additional metadata.
+pub(crate) fn token_metadata_space(
+    name_len: usize,
+    symbol_len: usize,
+    uri_len: usize,
+    additional_metadata: impl IntoIterator<Item = (usize, usize)>,
+) -> u64 {
+    let additional_metadata_len: usize = additional_metadata
+        .into_iter()
+        .map(|(key_len, value_len)| 4 + key_len + 4 + value_len)
+        .sum();
+    let len = 32 // update_authority
+        + 32 // mint
+        + 4 + name_len
+        + 4 + symbol_len
+        + 4 + uri_len
+        + 4 + additional_metadata_len;
+    TLV_HEADER_LEN + len as u64
+}
+
+impl From<ExtensionType> for [u8; 2] {
+    fn from(a: ExtensionType) -> Self {
+        u16::from(a).to_le_bytes()
+    }
+}
+
+/// Writes the canonical extension name, e.g. `MintCloseAuthority`.
+impl Display for ExtensionType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mint_account_size_counts_each_extension_once() {
+        assert_eq!(
+            calculate_mint_account_size(&[
+                ExtensionType::MintCloseAuthority,
+                ExtensionType::MintCloseAuthority,
+            ]),
+            calculate_mint_account_size(&[ExtensionType::MintCloseAuthority]),
+        );
+    }
+
+    #[test]
+    fn mint_account_size_avoids_multisig_len() {
+        let size = calculate_mint_account_size(&[
+            ExtensionType::ConfidentialTransferFeeConfig,
+            ExtensionType::InterestBearingConfig,
+        ]);
+        assert_eq!(size, MULTISIG_LEN + 2);
+    }
+}
@@ -6,12 +6,17 @@ pub mod extension;
 pub mod types;
 pub mod update_metadata_ix;
 
+use crate::compute_budget::compute_budget::ComputeBudgetInstruction;
 use crate::metaplex::types::CreateArgs;
+use crate::metaplex::types::PrintArgs;
 use crate::metaplex::types::{
-    AuthorizationData, CollectionDetailsToggle, CollectionToggle, Data, RuleSetToggle, UsesToggle,
+    AuthorizationData, BuilderError, CollectionDetailsToggle, CollectionToggle, Creator, Data,
+    RuleSetToggle, UsesToggle, VerificationArgs,
 };
+use crate::token::constants::associated_account_program_id;
 use crate::token::constants::system_program_id;
 use crate::token::constants::sysvar_program_id;
+use crate::token::constants::token_program_id;
 use ic_solana::types::instruction;
 use ic_solana::types::Pubkey;
 // use borsh::BorshDeserialize;
@@ -20,12 +25,19 @@ use borsh_derive::BorshDeserialize;
 use borsh_derive::BorshSerialize;
 pub const METADATA_PREFIX: &str = "metadata";
 pub const TOKEN_RECORD_SEED: &str = "token_record";
+pub const EDITION_MARKER_BIT_SIZE: u64 = 248;
+pub const EDITION_MARKER_V2_SEED: &str = "marker";
+pub const PROGRAM_SIGNER_SEED: &str = "signer";
 
 pub fn metadata_program_id() -> Pubkey {
     Pubkey::from_str("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s").unwrap()
 }
 
 pub fn derive_metadata_pda(pubkey: &Pubkey) -> Pubkey {
+    derive_metadata_pda_with_bump(pubkey).0
+}
+
+pub fn derive_metadata_pda_with_bump(pubkey: &Pubkey) -> (Pubkey, u8) {
     let metaplex_pubkey = metadata_program_id();
 
     let seeds = &[
@@ -34,12 +46,15 @@ pub fn derive_metadata_pda(pubkey: &Pubkey) -> Pubkey {
         pubkey.as_ref(),
     ];
 
-    let (pda, _) = Pubkey::find_program_address(seeds, &metaplex_pubkey);
-    pda
+    Pubkey::find_program_address(seeds, &metaplex_pubkey)
 }
 
 pub fn derive_token_record_pda(mint: &Pubkey, token: &Pubkey) -> Pubkey {
-    let (pda, _bump) = Pubkey::find_program_address(
+    derive_token_record_pda_with_bump(mint, token).0
+}
+
+pub fn derive_token_record_pda_with_bump(mint: &Pubkey, token: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
         &[
             METADATA_PREFIX.as_bytes(),
             metadata_program_id().as_ref(),
@@ -48,9 +63,7 @@ pub fn derive_token_record_pda(mint: &Pubkey, token: &Pubkey) -> Pubkey {
             token.as_ref(),
         ],
         &metadata_program_id(),
-    );
-
-    pda
+    )
 }
 
 pub fn derive_edition_pda(pubkey: &Pubkey) -> Pubkey {
@@ -67,6 +80,72 @@ pub fn derive_edition_pda(pubkey: &Pubkey) -> Pubkey {
     pda
 }
 
+/// Each edition marker tracks `EDITION_MARKER_BIT_SIZE` editions, so the marker
+/// for a given edition number is derived from `edition_number / 248`.
+pub fn derive_edition_marker_pda(mint: &Pubkey, edition_number: u64) -> Pubkey {
+    let metaplex_pubkey = metadata_program_id();
+    let edition_index = (edition_number / EDITION_MARKER_BIT_SIZE).to_string();
+
+    let seeds = &[
+        "metadata".as_bytes(),
+        metaplex_pubkey.as_ref(),
+        mint.as_ref(),
+        "edition".as_bytes(),
+        edition_index.as_bytes(),
+    ];
+
+    let (pda, _) = Pubkey::find_program_address(seeds, &metaplex_pubkey);
+    pda
+}
+
+/// The v2 edition marker is a single ledger per master edition, so unlike
+/// `derive_edition_marker_pda` it does not depend on the edition number.
+pub fn derive_edition_marker_v2_pda(mint: &Pubkey) -> Pubkey {
+    let metaplex_pubkey = metadata_program_id();
+
+    let seeds = &[
+        "metadata".as_bytes(),
+        metaplex_pubkey.as_ref(),
+        mint.as_ref(),
+        "edition".as_bytes(),
+        EDITION_MARKER_V2_SEED.as_bytes(),
+    ];
+
+    let (pda, _) = Pubkey::find_program_address(seeds, &metaplex_pubkey);
+    pda
+}
+
+pub fn derive_program_as_signer_pda() -> Pubkey {
+    let metaplex_pubkey = metadata_program_id();
+
+    let seeds = &[
+        METADATA_PREFIX.as_bytes(),
+        metaplex_pubkey.as_ref(),
+        PROGRAM_SIGNER_SEED.as_bytes(),
+    ];
+
+    let (pda, _) = Pubkey::find_program_address(seeds, &metaplex_pubkey);
+    pda
+}
+
+/// Metadata pdas of `mints`, in the same order.
+pub fn derive_metadata_pdas(mints: &[Pubkey]) -> Vec<Pubkey> {
+    mints.iter().map(derive_metadata_pda).collect()
+}
+
+/// Edition pdas of `mints`, in the same order.
+pub fn derive_edition_pdas(mints: &[Pubkey]) -> Vec<Pubkey> {
+    mints.iter().map(derive_edition_pda).collect()
+}
+
+/// Token record pdas of `tokens` holding `mint`, in the same order.
+pub fn derive_token_record_pdas(mint: &Pubkey, tokens: &[Pubkey]) -> Vec<Pubkey> {
+    tokens
+        .iter()
+        .map(|token| derive_token_record_pda(mint, token))
+        .collect()
+}
+
 /// Accounts.
 pub struct Create {
     /// Unallocated metadata account with address as pda of ['metadata', program id, mint id]
@@ -162,7 +241,10 @@ impl CreateInstructionData {
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub struct CreateInstructionArgs {
     pub create_args: CreateArgs,
 }
@@ -199,6 +281,16 @@ impl CreateBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
     /// Unallocated metadata account with address as pda of ['metadata', program id, mint id]
     #[inline(always)]
     pub fn metadata(&mut self, metadata: Pubkey) -> &mut Self {
@@ -274,24 +366,71 @@ impl CreateBuilder {
         self.__remaining_accounts.extend_from_slice(accounts);
         self
     }
+    /// Checks that all required fields are set without building the instruction.
+    pub fn validate(&self) -> Result<(), BuilderError> {
+        if self.metadata.is_none() {
+            return Err(BuilderError::MissingField("metadata"));
+        }
+        if self.mint.is_none() {
+            return Err(BuilderError::MissingField("mint"));
+        }
+        if self.authority.is_none() {
+            return Err(BuilderError::MissingField("authority"));
+        }
+        if self.payer.is_none() {
+            return Err(BuilderError::MissingField("payer"));
+        }
+        if self.update_authority.is_none() {
+            return Err(BuilderError::MissingField("update_authority"));
+        }
+        if self.create_args.is_none() {
+            return Err(BuilderError::MissingField("create_args"));
+        }
+        Ok(())
+    }
     #[allow(clippy::clone_on_copy)]
-    pub fn instruction(&self) -> instruction::Instruction {
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
         let accounts = Create {
-            metadata: self.metadata.expect("metadata is not set"),
+            metadata: self
+                .metadata
+                .ok_or(BuilderError::MissingField("metadata"))?,
             master_edition: self.master_edition,
-            mint: self.mint.expect("mint is not set"),
-            authority: self.authority.expect("authority is not set"),
-            payer: self.payer.expect("payer is not set"),
-            update_authority: self.update_authority.expect("update_authority is not set"),
+            mint: self.mint.ok_or(BuilderError::MissingField("mint"))?,
+            authority: self
+                .authority
+                .ok_or(BuilderError::MissingField("authority"))?,
+            payer: self.payer.ok_or(BuilderError::MissingField("payer"))?,
+            update_authority: self
+                .update_authority
+                .ok_or(BuilderError::MissingField("update_authority"))?,
             system_program: self.system_program.unwrap_or(system_program_id()),
             sysvar_instructions: self.sysvar_instructions.unwrap_or(sysvar_program_id()),
             spl_token_program: self.spl_token_program,
         };
         let args = CreateInstructionArgs {
-            create_args: self.create_args.clone().expect("create_args is not set"),
+            create_args: self
+                .create_args
+                .clone()
+                .ok_or(BuilderError::MissingField("create_args"))?,
         };
 
-        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+        Ok(accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts))
+    }
+    /// Builds the instruction preceded by `SetComputeUnitLimit` and `SetComputeUnitPrice`.
+    pub fn instruction_with_compute_budget(
+        &self,
+        cu_limit: u32,
+        micro_lamports: u64,
+    ) -> Result<Vec<instruction::Instruction>, BuilderError> {
+        Ok(vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(cu_limit),
+            ComputeBudgetInstruction::set_compute_unit_price(micro_lamports),
+            self.instruction()?,
+        ])
+    }
+    /// Same as [`CreateBuilder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
     }
 }
 
@@ -422,7 +561,10 @@ impl UpdateV1InstructionData {
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub struct UpdateV1InstructionArgs {
     pub new_update_authority: Option<Pubkey>,
     pub data: Option<Data>,
@@ -465,6 +607,7 @@ pub struct UpdateV1Builder {
     authorization_rules: Option<Pubkey>,
     new_update_authority: Option<Pubkey>,
     data: Option<Data>,
+    creators: Option<Option<Vec<Creator>>>,
     primary_sale_happened: Option<bool>,
     is_mutable: Option<bool>,
     collection: Option<CollectionToggle>,
@@ -479,6 +622,16 @@ impl UpdateV1Builder {
     pub fn new() -> Self {
         Self::default()
     }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
     /// Update authority or delegate
     #[inline(always)]
     pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
@@ -568,6 +721,33 @@ impl UpdateV1Builder {
         self
     }
     /// `[optional argument]`
+    /// Sets the creators, merged into `data` when the instruction is built.
+    ///
+    /// `data` replaces all of the on-chain fields, so building fails unless
+    /// `data` is also set to the current name, symbol, uri and seller fee.
+    #[inline(always)]
+    pub fn set_creators(&mut self, creators: Vec<Creator>) -> &mut Self {
+        self.creators = Some(Some(creators));
+        self
+    }
+    /// `[optional argument]`
+    /// Removes the creators, merged into `data` like [`Self::set_creators`].
+    #[inline(always)]
+    pub fn clear_creators(&mut self) -> &mut Self {
+        self.creators = Some(None);
+        self
+    }
+    fn merged_data(&self) -> Result<Option<Data>, BuilderError> {
+        match (&self.data, &self.creators) {
+            (data, None) => Ok(data.clone()),
+            (Some(data), Some(creators)) => Ok(Some(Data {
+                creators: creators.clone(),
+                ..data.clone()
+            })),
+            (None, Some(_)) => Err(BuilderError::MissingField("data")),
+        }
+    }
+    /// `[optional argument]`
     #[inline(always)]
     pub fn primary_sale_happened(&mut self, primary_sale_happened: bool) -> &mut Self {
         self.primary_sale_happened = Some(primary_sale_happened);
@@ -621,16 +801,39 @@ impl UpdateV1Builder {
         self.__remaining_accounts.extend_from_slice(accounts);
         self
     }
+    /// Checks that all required fields are set without building the instruction.
+    pub fn validate(&self) -> Result<(), BuilderError> {
+        if self.authority.is_none() {
+            return Err(BuilderError::MissingField("authority"));
+        }
+        if self.mint.is_none() {
+            return Err(BuilderError::MissingField("mint"));
+        }
+        if self.metadata.is_none() {
+            return Err(BuilderError::MissingField("metadata"));
+        }
+        if self.payer.is_none() {
+            return Err(BuilderError::MissingField("payer"));
+        }
+        if self.data.is_none() && self.creators.is_some() {
+            return Err(BuilderError::MissingField("data"));
+        }
+        Ok(())
+    }
     #[allow(clippy::clone_on_copy)]
-    pub fn instruction(&self) -> instruction::Instruction {
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
         let accounts = UpdateV1 {
-            authority: self.authority.expect("authority is not set"),
+            authority: self
+                .authority
+                .ok_or(BuilderError::MissingField("authority"))?,
             delegate_record: self.delegate_record,
             token: self.token,
-            mint: self.mint.expect("mint is not set"),
-            metadata: self.metadata.expect("metadata is not set"),
+            mint: self.mint.ok_or(BuilderError::MissingField("mint"))?,
+            metadata: self
+                .metadata
+                .ok_or(BuilderError::MissingField("metadata"))?,
             edition: self.edition,
-            payer: self.payer.expect("payer is not set"),
+            payer: self.payer.ok_or(BuilderError::MissingField("payer"))?,
             system_program: self.system_program.unwrap_or(system_program_id()),
             sysvar_instructions: self.sysvar_instructions.unwrap_or(sysvar_program_id()),
             authorization_rules_program: self.authorization_rules_program,
@@ -638,7 +841,7 @@ impl UpdateV1Builder {
         };
         let args = UpdateV1InstructionArgs {
             new_update_authority: self.new_update_authority.clone(),
-            data: self.data.clone(),
+            data: self.merged_data()?,
             primary_sale_happened: self.primary_sale_happened.clone(),
             is_mutable: self.is_mutable.clone(),
             collection: self.collection.clone().unwrap_or(CollectionToggle::None),
@@ -651,6 +854,3057 @@ impl UpdateV1Builder {
             authorization_data: self.authorization_data.clone(),
         };
 
-        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+        Ok(accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts))
+    }
+    /// Same as [`UpdateV1Builder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
+    }
+    /// Builds the instruction preceded by `SetComputeUnitLimit` and `SetComputeUnitPrice`.
+    pub fn instruction_with_compute_budget(
+        &self,
+        cu_limit: u32,
+        micro_lamports: u64,
+    ) -> Result<Vec<instruction::Instruction>, BuilderError> {
+        Ok(vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(cu_limit),
+            ComputeBudgetInstruction::set_compute_unit_price(micro_lamports),
+            self.instruction()?,
+        ])
+    }
+}
+
+/// Accounts.
+pub struct LockV1 {
+    /// Delegate or freeze authority
+    pub authority: Pubkey,
+    /// Token owner account
+    pub token_owner: Option<Pubkey>,
+    /// Token account
+    pub token: Pubkey,
+    /// Mint account
+    pub mint: Pubkey,
+    /// Metadata account
+    pub metadata: Pubkey,
+    /// Edition account
+    pub edition: Option<Pubkey>,
+    /// Token record account
+    pub token_record: Option<Pubkey>,
+    /// Payer
+    pub payer: Pubkey,
+    /// System program
+    pub system_program: Pubkey,
+    /// Instructions sysvar account
+    pub sysvar_instructions: Pubkey,
+    /// SPL Token Program
+    pub spl_token_program: Option<Pubkey>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<Pubkey>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<Pubkey>,
+}
+
+impl LockV1 {
+    pub fn instruction(&self, args: LockV1InstructionArgs) -> instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: LockV1InstructionArgs,
+        remaining_accounts: &[instruction::AccountMeta],
+    ) -> instruction::Instruction {
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        accounts.push(instruction::AccountMeta::new_readonly(self.authority, true));
+        if let Some(token_owner) = self.token_owner {
+            accounts.push(instruction::AccountMeta::new_readonly(token_owner, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new(self.token, false));
+        accounts.push(instruction::AccountMeta::new_readonly(self.mint, false));
+        accounts.push(instruction::AccountMeta::new(self.metadata, false));
+        if let Some(edition) = self.edition {
+            accounts.push(instruction::AccountMeta::new_readonly(edition, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(token_record) = self.token_record {
+            accounts.push(instruction::AccountMeta::new(token_record, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new(self.payer, true));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.sysvar_instructions,
+            false,
+        ));
+        if let Some(spl_token_program) = self.spl_token_program {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                spl_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                authorization_rules_program,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                authorization_rules,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&LockV1InstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        instruction::Instruction {
+            program_id: metadata_program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct LockV1InstructionData {
+    discriminator: u8,
+    lock_v1_discriminator: u8,
+}
+
+impl LockV1InstructionData {
+    fn new() -> Self {
+        Self {
+            discriminator: 46,
+            lock_v1_discriminator: 0,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct LockV1InstructionArgs {
+    pub authorization_data: Option<AuthorizationData>,
+}
+
+/// Instruction builder for `LockV1`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[optional]` token_owner
+///   2. `[writable]` token
+///   3. `[]` mint
+///   4. `[writable]` metadata
+///   5. `[optional]` edition
+///   6. `[writable, optional]` token_record
+///   7. `[writable, signer]` payer
+///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   9. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
+///   10. `[optional]` spl_token_program
+///   11. `[optional]` authorization_rules_program
+///   12. `[optional]` authorization_rules
+#[derive(Default)]
+pub struct LockV1Builder {
+    authority: Option<Pubkey>,
+    token_owner: Option<Pubkey>,
+    token: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    sysvar_instructions: Option<Pubkey>,
+    spl_token_program: Option<Pubkey>,
+    authorization_rules_program: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    authorization_data: Option<AuthorizationData>,
+    __remaining_accounts: Vec<instruction::AccountMeta>,
+}
+
+impl LockV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
+    /// Delegate or freeze authority
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// Token owner account
+    #[inline(always)]
+    pub fn token_owner(&mut self, token_owner: Option<Pubkey>) -> &mut Self {
+        self.token_owner = token_owner;
+        self
+    }
+    /// Token account
+    #[inline(always)]
+    pub fn token(&mut self, token: Pubkey) -> &mut Self {
+        self.token = Some(token);
+        self
+    }
+    /// Mint account
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Metadata account
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// `[optional account]`
+    /// Edition account
+    #[inline(always)]
+    pub fn edition(&mut self, edition: Option<Pubkey>) -> &mut Self {
+        self.edition = edition;
+        self
+    }
+    /// `[optional account]`
+    /// Token record account
+    #[inline(always)]
+    pub fn token_record(&mut self, token_record: Option<Pubkey>) -> &mut Self {
+        self.token_record = token_record;
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(&mut self, sysvar_instructions: Pubkey) -> &mut Self {
+        self.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    /// `[optional account]`
+    /// SPL Token Program
+    #[inline(always)]
+    pub fn spl_token_program(&mut self, spl_token_program: Option<Pubkey>) -> &mut Self {
+        self.spl_token_program = spl_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(&mut self, authorization_rules: Option<Pubkey>) -> &mut Self {
+        self.authorization_rules = authorization_rules;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn authorization_data(&mut self, authorization_data: AuthorizationData) -> &mut Self {
+        self.authorization_data = Some(authorization_data);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(&mut self, account: instruction::AccountMeta) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(&mut self, accounts: &[instruction::AccountMeta]) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
+        let accounts = LockV1 {
+            authority: self
+                .authority
+                .ok_or(BuilderError::MissingField("authority"))?,
+            token_owner: self.token_owner,
+            token: self.token.ok_or(BuilderError::MissingField("token"))?,
+            mint: self.mint.ok_or(BuilderError::MissingField("mint"))?,
+            metadata: self
+                .metadata
+                .ok_or(BuilderError::MissingField("metadata"))?,
+            edition: self.edition,
+            token_record: self.token_record,
+            payer: self.payer.ok_or(BuilderError::MissingField("payer"))?,
+            system_program: self.system_program.unwrap_or(system_program_id()),
+            sysvar_instructions: self.sysvar_instructions.unwrap_or(sysvar_program_id()),
+            spl_token_program: self.spl_token_program,
+            authorization_rules_program: self.authorization_rules_program,
+            authorization_rules: self.authorization_rules,
+        };
+        let args = LockV1InstructionArgs {
+            authorization_data: self.authorization_data.clone(),
+        };
+
+        Ok(accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts))
+    }
+    /// Same as [`LockV1Builder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Accounts.
+pub struct UnlockV1 {
+    /// Delegate or freeze authority
+    pub authority: Pubkey,
+    /// Token owner account
+    pub token_owner: Option<Pubkey>,
+    /// Token account
+    pub token: Pubkey,
+    /// Mint account
+    pub mint: Pubkey,
+    /// Metadata account
+    pub metadata: Pubkey,
+    /// Edition account
+    pub edition: Option<Pubkey>,
+    /// Token record account
+    pub token_record: Option<Pubkey>,
+    /// Payer
+    pub payer: Pubkey,
+    /// System program
+    pub system_program: Pubkey,
+    /// Instructions sysvar account
+    pub sysvar_instructions: Pubkey,
+    /// SPL Token Program
+    pub spl_token_program: Option<Pubkey>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<Pubkey>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<Pubkey>,
+}
+
+impl UnlockV1 {
+    pub fn instruction(&self, args: UnlockV1InstructionArgs) -> instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: UnlockV1InstructionArgs,
+        remaining_accounts: &[instruction::AccountMeta],
+    ) -> instruction::Instruction {
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        accounts.push(instruction::AccountMeta::new_readonly(self.authority, true));
+        if let Some(token_owner) = self.token_owner {
+            accounts.push(instruction::AccountMeta::new_readonly(token_owner, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new(self.token, false));
+        accounts.push(instruction::AccountMeta::new_readonly(self.mint, false));
+        accounts.push(instruction::AccountMeta::new(self.metadata, false));
+        if let Some(edition) = self.edition {
+            accounts.push(instruction::AccountMeta::new_readonly(edition, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(token_record) = self.token_record {
+            accounts.push(instruction::AccountMeta::new(token_record, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new(self.payer, true));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.sysvar_instructions,
+            false,
+        ));
+        if let Some(spl_token_program) = self.spl_token_program {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                spl_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                authorization_rules_program,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                authorization_rules,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&UnlockV1InstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        instruction::Instruction {
+            program_id: metadata_program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct UnlockV1InstructionData {
+    discriminator: u8,
+    unlock_v1_discriminator: u8,
+}
+
+impl UnlockV1InstructionData {
+    fn new() -> Self {
+        Self {
+            discriminator: 47,
+            unlock_v1_discriminator: 0,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct UnlockV1InstructionArgs {
+    pub authorization_data: Option<AuthorizationData>,
+}
+
+/// Instruction builder for `UnlockV1`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[optional]` token_owner
+///   2. `[writable]` token
+///   3. `[]` mint
+///   4. `[writable]` metadata
+///   5. `[optional]` edition
+///   6. `[writable, optional]` token_record
+///   7. `[writable, signer]` payer
+///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   9. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
+///   10. `[optional]` spl_token_program
+///   11. `[optional]` authorization_rules_program
+///   12. `[optional]` authorization_rules
+#[derive(Default)]
+pub struct UnlockV1Builder {
+    authority: Option<Pubkey>,
+    token_owner: Option<Pubkey>,
+    token: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    sysvar_instructions: Option<Pubkey>,
+    spl_token_program: Option<Pubkey>,
+    authorization_rules_program: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    authorization_data: Option<AuthorizationData>,
+    __remaining_accounts: Vec<instruction::AccountMeta>,
+}
+
+impl UnlockV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
+    /// Delegate or freeze authority
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// Token owner account
+    #[inline(always)]
+    pub fn token_owner(&mut self, token_owner: Option<Pubkey>) -> &mut Self {
+        self.token_owner = token_owner;
+        self
+    }
+    /// Token account
+    #[inline(always)]
+    pub fn token(&mut self, token: Pubkey) -> &mut Self {
+        self.token = Some(token);
+        self
+    }
+    /// Mint account
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Metadata account
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// `[optional account]`
+    /// Edition account
+    #[inline(always)]
+    pub fn edition(&mut self, edition: Option<Pubkey>) -> &mut Self {
+        self.edition = edition;
+        self
+    }
+    /// `[optional account]`
+    /// Token record account
+    #[inline(always)]
+    pub fn token_record(&mut self, token_record: Option<Pubkey>) -> &mut Self {
+        self.token_record = token_record;
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(&mut self, sysvar_instructions: Pubkey) -> &mut Self {
+        self.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    /// `[optional account]`
+    /// SPL Token Program
+    #[inline(always)]
+    pub fn spl_token_program(&mut self, spl_token_program: Option<Pubkey>) -> &mut Self {
+        self.spl_token_program = spl_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(&mut self, authorization_rules: Option<Pubkey>) -> &mut Self {
+        self.authorization_rules = authorization_rules;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn authorization_data(&mut self, authorization_data: AuthorizationData) -> &mut Self {
+        self.authorization_data = Some(authorization_data);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(&mut self, account: instruction::AccountMeta) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(&mut self, accounts: &[instruction::AccountMeta]) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
+        let accounts = UnlockV1 {
+            authority: self
+                .authority
+                .ok_or(BuilderError::MissingField("authority"))?,
+            token_owner: self.token_owner,
+            token: self.token.ok_or(BuilderError::MissingField("token"))?,
+            mint: self.mint.ok_or(BuilderError::MissingField("mint"))?,
+            metadata: self
+                .metadata
+                .ok_or(BuilderError::MissingField("metadata"))?,
+            edition: self.edition,
+            token_record: self.token_record,
+            payer: self.payer.ok_or(BuilderError::MissingField("payer"))?,
+            system_program: self.system_program.unwrap_or(system_program_id()),
+            sysvar_instructions: self.sysvar_instructions.unwrap_or(sysvar_program_id()),
+            spl_token_program: self.spl_token_program,
+            authorization_rules_program: self.authorization_rules_program,
+            authorization_rules: self.authorization_rules,
+        };
+        let args = UnlockV1InstructionArgs {
+            authorization_data: self.authorization_data.clone(),
+        };
+
+        Ok(accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts))
+    }
+    /// Same as [`UnlockV1Builder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Accounts.
+pub struct TransferV1 {
+    /// Token account
+    pub token: Pubkey,
+    /// Token account owner
+    pub token_owner: Pubkey,
+    /// Destination token account
+    pub destination_token: Pubkey,
+    /// Destination token account owner
+    pub destination_owner: Pubkey,
+    /// Mint of token asset
+    pub mint: Pubkey,
+    /// Metadata (pda of ['metadata', program id, mint id])
+    pub metadata: Pubkey,
+    /// Edition of token asset
+    pub edition: Option<Pubkey>,
+    /// Owner token record account
+    pub token_record: Option<Pubkey>,
+    /// Destination token record account
+    pub destination_token_record: Option<Pubkey>,
+    /// Transfer authority (token owner or delegate)
+    pub authority: Pubkey,
+    /// Payer
+    pub payer: Pubkey,
+    /// System Program
+    pub system_program: Pubkey,
+    /// Instructions sysvar account
+    pub sysvar_instructions: Pubkey,
+    /// SPL Token Program
+    pub spl_token_program: Pubkey,
+    /// SPL Associated Token Account program
+    pub spl_ata_program: Pubkey,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<Pubkey>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<Pubkey>,
+}
+
+impl TransferV1 {
+    pub fn instruction(&self, args: TransferV1InstructionArgs) -> instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: TransferV1InstructionArgs,
+        remaining_accounts: &[instruction::AccountMeta],
+    ) -> instruction::Instruction {
+        let mut accounts = Vec::with_capacity(17 + remaining_accounts.len());
+        accounts.push(instruction::AccountMeta::new(self.token, false));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.token_owner,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new(self.destination_token, false));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.destination_owner,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(self.mint, false));
+        accounts.push(instruction::AccountMeta::new(self.metadata, false));
+        if let Some(edition) = self.edition {
+            accounts.push(instruction::AccountMeta::new_readonly(edition, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(token_record) = self.token_record {
+            accounts.push(instruction::AccountMeta::new(token_record, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(destination_token_record) = self.destination_token_record {
+            accounts.push(instruction::AccountMeta::new(
+                destination_token_record,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new_readonly(self.authority, true));
+        accounts.push(instruction::AccountMeta::new(self.payer, true));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.sysvar_instructions,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.spl_token_program,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.spl_ata_program,
+            false,
+        ));
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                authorization_rules_program,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                authorization_rules,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&TransferV1InstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        instruction::Instruction {
+            program_id: metadata_program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct TransferV1InstructionData {
+    discriminator: u8,
+    transfer_v1_discriminator: u8,
+}
+
+impl TransferV1InstructionData {
+    fn new() -> Self {
+        Self {
+            discriminator: 49,
+            transfer_v1_discriminator: 0,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct TransferV1InstructionArgs {
+    pub amount: u64,
+    pub authorization_data: Option<AuthorizationData>,
+}
+
+/// Instruction builder for `TransferV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` token
+///   1. `[]` token_owner
+///   2. `[writable]` destination_token
+///   3. `[]` destination_owner
+///   4. `[]` mint
+///   5. `[writable]` metadata
+///   6. `[optional]` edition
+///   7. `[writable, optional]` token_record
+///   8. `[writable, optional]` destination_token_record
+///   9. `[signer]` authority
+///   10. `[writable, signer]` payer
+///   11. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   12. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
+///   13. `[optional]` spl_token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   14. `[optional]` spl_ata_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   15. `[optional]` authorization_rules_program
+///   16. `[optional]` authorization_rules
+#[derive(Default)]
+pub struct TransferV1Builder {
+    token: Option<Pubkey>,
+    token_owner: Option<Pubkey>,
+    destination_token: Option<Pubkey>,
+    destination_owner: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    destination_token_record: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    sysvar_instructions: Option<Pubkey>,
+    spl_token_program: Option<Pubkey>,
+    spl_ata_program: Option<Pubkey>,
+    authorization_rules_program: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    amount: Option<u64>,
+    authorization_data: Option<AuthorizationData>,
+    __remaining_accounts: Vec<instruction::AccountMeta>,
+}
+
+impl TransferV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
+    /// Token account
+    #[inline(always)]
+    pub fn token(&mut self, token: Pubkey) -> &mut Self {
+        self.token = Some(token);
+        self
+    }
+    /// Token account owner
+    #[inline(always)]
+    pub fn token_owner(&mut self, token_owner: Pubkey) -> &mut Self {
+        self.token_owner = Some(token_owner);
+        self
+    }
+    /// Destination token account
+    #[inline(always)]
+    pub fn destination_token(&mut self, destination_token: Pubkey) -> &mut Self {
+        self.destination_token = Some(destination_token);
+        self
+    }
+    /// Destination token account owner
+    #[inline(always)]
+    pub fn destination_owner(&mut self, destination_owner: Pubkey) -> &mut Self {
+        self.destination_owner = Some(destination_owner);
+        self
+    }
+    /// Mint of token asset
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Metadata (pda of ['metadata', program id, mint id])
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// `[optional account]`
+    /// Edition of token asset
+    #[inline(always)]
+    pub fn edition(&mut self, edition: Option<Pubkey>) -> &mut Self {
+        self.edition = edition;
+        self
+    }
+    /// `[optional account]`
+    /// Owner token record account
+    #[inline(always)]
+    pub fn token_record(&mut self, token_record: Option<Pubkey>) -> &mut Self {
+        self.token_record = token_record;
+        self
+    }
+    /// `[optional account]`
+    /// Destination token record account
+    #[inline(always)]
+    pub fn destination_token_record(
+        &mut self,
+        destination_token_record: Option<Pubkey>,
+    ) -> &mut Self {
+        self.destination_token_record = destination_token_record;
+        self
+    }
+    /// Transfer authority (token owner or delegate)
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System Program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(&mut self, sysvar_instructions: Pubkey) -> &mut Self {
+        self.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// SPL Token Program
+    #[inline(always)]
+    pub fn spl_token_program(&mut self, spl_token_program: Pubkey) -> &mut Self {
+        self.spl_token_program = Some(spl_token_program);
+        self
+    }
+    /// `[optional account, default to 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL']`
+    /// SPL Associated Token Account program
+    #[inline(always)]
+    pub fn spl_ata_program(&mut self, spl_ata_program: Pubkey) -> &mut Self {
+        self.spl_ata_program = Some(spl_ata_program);
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(&mut self, authorization_rules: Option<Pubkey>) -> &mut Self {
+        self.authorization_rules = authorization_rules;
+        self
+    }
+    /// `[optional argument, defaults to '1']`
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn authorization_data(&mut self, authorization_data: AuthorizationData) -> &mut Self {
+        self.authorization_data = Some(authorization_data);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(&mut self, account: instruction::AccountMeta) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(&mut self, accounts: &[instruction::AccountMeta]) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
+        let accounts = TransferV1 {
+            token: self.token.ok_or(BuilderError::MissingField("token"))?,
+            token_owner: self
+                .token_owner
+                .ok_or(BuilderError::MissingField("token_owner"))?,
+            destination_token: self
+                .destination_token
+                .ok_or(BuilderError::MissingField("destination_token"))?,
+            destination_owner: self
+                .destination_owner
+                .ok_or(BuilderError::MissingField("destination_owner"))?,
+            mint: self.mint.ok_or(BuilderError::MissingField("mint"))?,
+            metadata: self
+                .metadata
+                .ok_or(BuilderError::MissingField("metadata"))?,
+            edition: self.edition,
+            token_record: self.token_record,
+            destination_token_record: self.destination_token_record,
+            authority: self
+                .authority
+                .ok_or(BuilderError::MissingField("authority"))?,
+            payer: self.payer.ok_or(BuilderError::MissingField("payer"))?,
+            system_program: self.system_program.unwrap_or(system_program_id()),
+            sysvar_instructions: self.sysvar_instructions.unwrap_or(sysvar_program_id()),
+            spl_token_program: self.spl_token_program.unwrap_or(token_program_id()),
+            spl_ata_program: self
+                .spl_ata_program
+                .unwrap_or(associated_account_program_id()),
+            authorization_rules_program: self.authorization_rules_program,
+            authorization_rules: self.authorization_rules,
+        };
+        let args = TransferV1InstructionArgs {
+            amount: self.amount.clone().unwrap_or(1),
+            authorization_data: self.authorization_data.clone(),
+        };
+
+        Ok(accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts))
+    }
+    /// Same as [`TransferV1Builder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Accounts.
+pub struct VerifyCreatorV1 {
+    /// Creator to verify, collection update authority or delegate
+    pub authority: Pubkey,
+    /// Delegate record PDA
+    pub delegate_record: Option<Pubkey>,
+    /// Metadata account
+    pub metadata: Pubkey,
+    /// Mint of the Collection
+    pub collection_mint: Option<Pubkey>,
+    /// Metadata Account of the Collection
+    pub collection_metadata: Option<Pubkey>,
+    /// Master Edition Account of the Collection Token
+    pub collection_master_edition: Option<Pubkey>,
+    /// System program
+    pub system_program: Pubkey,
+    /// Instructions sysvar account
+    pub sysvar_instructions: Pubkey,
+}
+
+impl VerifyCreatorV1 {
+    pub fn instruction(&self) -> instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[instruction::AccountMeta],
+    ) -> instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(instruction::AccountMeta::new_readonly(self.authority, true));
+        if let Some(delegate_record) = self.delegate_record {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                delegate_record,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new(self.metadata, false));
+        if let Some(collection_mint) = self.collection_mint {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                collection_mint,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(collection_metadata) = self.collection_metadata {
+            accounts.push(instruction::AccountMeta::new(collection_metadata, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(collection_master_edition) = self.collection_master_edition {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                collection_master_edition,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.sysvar_instructions,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&VerifyCreatorV1InstructionData::new()).unwrap();
+
+        instruction::Instruction {
+            program_id: metadata_program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct VerifyCreatorV1InstructionData {
+    discriminator: u8,
+    verification_args: VerificationArgs,
+}
+
+impl VerifyCreatorV1InstructionData {
+    fn new() -> Self {
+        Self {
+            discriminator: 52,
+            verification_args: VerificationArgs::CreatorV1,
+        }
+    }
+}
+
+/// Instruction builder for `VerifyCreatorV1`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[optional]` delegate_record
+///   2. `[writable]` metadata
+///   3. `[optional]` collection_mint
+///   4. `[writable, optional]` collection_metadata
+///   5. `[optional]` collection_master_edition
+///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
+#[derive(Default)]
+pub struct VerifyCreatorV1Builder {
+    authority: Option<Pubkey>,
+    delegate_record: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    collection_mint: Option<Pubkey>,
+    collection_metadata: Option<Pubkey>,
+    collection_master_edition: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    sysvar_instructions: Option<Pubkey>,
+    __remaining_accounts: Vec<instruction::AccountMeta>,
+}
+
+impl VerifyCreatorV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
+    /// Creator to verify, collection update authority or delegate
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// Delegate record PDA
+    #[inline(always)]
+    pub fn delegate_record(&mut self, delegate_record: Option<Pubkey>) -> &mut Self {
+        self.delegate_record = delegate_record;
+        self
+    }
+    /// Metadata account
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// `[optional account]`
+    /// Mint of the Collection
+    #[inline(always)]
+    pub fn collection_mint(&mut self, collection_mint: Option<Pubkey>) -> &mut Self {
+        self.collection_mint = collection_mint;
+        self
+    }
+    /// `[optional account]`
+    /// Metadata Account of the Collection
+    #[inline(always)]
+    pub fn collection_metadata(&mut self, collection_metadata: Option<Pubkey>) -> &mut Self {
+        self.collection_metadata = collection_metadata;
+        self
+    }
+    /// `[optional account]`
+    /// Master Edition Account of the Collection Token
+    #[inline(always)]
+    pub fn collection_master_edition(
+        &mut self,
+        collection_master_edition: Option<Pubkey>,
+    ) -> &mut Self {
+        self.collection_master_edition = collection_master_edition;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(&mut self, sysvar_instructions: Pubkey) -> &mut Self {
+        self.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(&mut self, account: instruction::AccountMeta) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(&mut self, accounts: &[instruction::AccountMeta]) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
+        let accounts = VerifyCreatorV1 {
+            authority: self
+                .authority
+                .ok_or(BuilderError::MissingField("authority"))?,
+            delegate_record: self.delegate_record,
+            metadata: self
+                .metadata
+                .ok_or(BuilderError::MissingField("metadata"))?,
+            collection_mint: self.collection_mint,
+            collection_metadata: self.collection_metadata,
+            collection_master_edition: self.collection_master_edition,
+            system_program: self.system_program.unwrap_or(system_program_id()),
+            sysvar_instructions: self.sysvar_instructions.unwrap_or(sysvar_program_id()),
+        };
+
+        Ok(accounts.instruction_with_remaining_accounts(&self.__remaining_accounts))
+    }
+    /// Same as [`VerifyCreatorV1Builder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Accounts.
+pub struct UnverifyCreatorV1 {
+    /// Creator to verify, collection (or metadata if parent burned) update authority or delegate
+    pub authority: Pubkey,
+    /// Delegate record PDA
+    pub delegate_record: Option<Pubkey>,
+    /// Metadata account
+    pub metadata: Pubkey,
+    /// Mint of the Collection
+    pub collection_mint: Option<Pubkey>,
+    /// Metadata Account of the Collection
+    pub collection_metadata: Option<Pubkey>,
+    /// System program
+    pub system_program: Pubkey,
+    /// Instructions sysvar account
+    pub sysvar_instructions: Pubkey,
+}
+
+impl UnverifyCreatorV1 {
+    pub fn instruction(&self) -> instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[instruction::AccountMeta],
+    ) -> instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(instruction::AccountMeta::new_readonly(self.authority, true));
+        if let Some(delegate_record) = self.delegate_record {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                delegate_record,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new(self.metadata, false));
+        if let Some(collection_mint) = self.collection_mint {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                collection_mint,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(collection_metadata) = self.collection_metadata {
+            accounts.push(instruction::AccountMeta::new(collection_metadata, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.sysvar_instructions,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&UnverifyCreatorV1InstructionData::new()).unwrap();
+
+        instruction::Instruction {
+            program_id: metadata_program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct UnverifyCreatorV1InstructionData {
+    discriminator: u8,
+    verification_args: VerificationArgs,
+}
+
+impl UnverifyCreatorV1InstructionData {
+    fn new() -> Self {
+        Self {
+            discriminator: 53,
+            verification_args: VerificationArgs::CreatorV1,
+        }
+    }
+}
+
+/// Instruction builder for `UnverifyCreatorV1`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[optional]` delegate_record
+///   2. `[writable]` metadata
+///   3. `[optional]` collection_mint
+///   4. `[writable, optional]` collection_metadata
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
+#[derive(Default)]
+pub struct UnverifyCreatorV1Builder {
+    authority: Option<Pubkey>,
+    delegate_record: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    collection_mint: Option<Pubkey>,
+    collection_metadata: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    sysvar_instructions: Option<Pubkey>,
+    __remaining_accounts: Vec<instruction::AccountMeta>,
+}
+
+impl UnverifyCreatorV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
+    /// Creator to verify, collection (or metadata if parent burned) update authority or delegate
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// Delegate record PDA
+    #[inline(always)]
+    pub fn delegate_record(&mut self, delegate_record: Option<Pubkey>) -> &mut Self {
+        self.delegate_record = delegate_record;
+        self
+    }
+    /// Metadata account
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// `[optional account]`
+    /// Mint of the Collection
+    #[inline(always)]
+    pub fn collection_mint(&mut self, collection_mint: Option<Pubkey>) -> &mut Self {
+        self.collection_mint = collection_mint;
+        self
+    }
+    /// `[optional account]`
+    /// Metadata Account of the Collection
+    #[inline(always)]
+    pub fn collection_metadata(&mut self, collection_metadata: Option<Pubkey>) -> &mut Self {
+        self.collection_metadata = collection_metadata;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(&mut self, sysvar_instructions: Pubkey) -> &mut Self {
+        self.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(&mut self, account: instruction::AccountMeta) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(&mut self, accounts: &[instruction::AccountMeta]) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
+        let accounts = UnverifyCreatorV1 {
+            authority: self
+                .authority
+                .ok_or(BuilderError::MissingField("authority"))?,
+            delegate_record: self.delegate_record,
+            metadata: self
+                .metadata
+                .ok_or(BuilderError::MissingField("metadata"))?,
+            collection_mint: self.collection_mint,
+            collection_metadata: self.collection_metadata,
+            system_program: self.system_program.unwrap_or(system_program_id()),
+            sysvar_instructions: self.sysvar_instructions.unwrap_or(sysvar_program_id()),
+        };
+
+        Ok(accounts.instruction_with_remaining_accounts(&self.__remaining_accounts))
+    }
+    /// Same as [`UnverifyCreatorV1Builder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Accounts.
+pub struct VerifyCollectionV1 {
+    /// Collection update authority or delegate
+    pub authority: Pubkey,
+    /// Delegate record PDA
+    pub delegate_record: Option<Pubkey>,
+    /// Metadata account
+    pub metadata: Pubkey,
+    /// Mint of the Collection
+    pub collection_mint: Pubkey,
+    /// Metadata Account of the Collection
+    pub collection_metadata: Option<Pubkey>,
+    /// Master Edition Account of the Collection Token
+    pub collection_master_edition: Option<Pubkey>,
+    /// System program
+    pub system_program: Pubkey,
+    /// Instructions sysvar account
+    pub sysvar_instructions: Pubkey,
+}
+
+impl VerifyCollectionV1 {
+    pub fn instruction(&self) -> instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[instruction::AccountMeta],
+    ) -> instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(instruction::AccountMeta::new_readonly(self.authority, true));
+        if let Some(delegate_record) = self.delegate_record {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                delegate_record,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new(self.metadata, false));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.collection_mint,
+            false,
+        ));
+        if let Some(collection_metadata) = self.collection_metadata {
+            accounts.push(instruction::AccountMeta::new(collection_metadata, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(collection_master_edition) = self.collection_master_edition {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                collection_master_edition,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.sysvar_instructions,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&VerifyCollectionV1InstructionData::new()).unwrap();
+
+        instruction::Instruction {
+            program_id: metadata_program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct VerifyCollectionV1InstructionData {
+    discriminator: u8,
+    verification_args: VerificationArgs,
+}
+
+impl VerifyCollectionV1InstructionData {
+    fn new() -> Self {
+        Self {
+            discriminator: 52,
+            verification_args: VerificationArgs::CollectionV1,
+        }
+    }
+}
+
+/// Instruction builder for `VerifyCollectionV1`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[optional]` delegate_record
+///   2. `[writable]` metadata
+///   3. `[]` collection_mint
+///   4. `[writable, optional]` collection_metadata
+///   5. `[optional]` collection_master_edition
+///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
+#[derive(Default)]
+pub struct VerifyCollectionV1Builder {
+    authority: Option<Pubkey>,
+    delegate_record: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    collection_mint: Option<Pubkey>,
+    collection_metadata: Option<Pubkey>,
+    collection_master_edition: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    sysvar_instructions: Option<Pubkey>,
+    __remaining_accounts: Vec<instruction::AccountMeta>,
+}
+
+impl VerifyCollectionV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
+    /// Collection update authority or delegate
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// Delegate record PDA
+    #[inline(always)]
+    pub fn delegate_record(&mut self, delegate_record: Option<Pubkey>) -> &mut Self {
+        self.delegate_record = delegate_record;
+        self
+    }
+    /// Metadata account
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// Mint of the Collection
+    #[inline(always)]
+    pub fn collection_mint(&mut self, collection_mint: Pubkey) -> &mut Self {
+        self.collection_mint = Some(collection_mint);
+        self
+    }
+    /// `[optional account]`
+    /// Metadata Account of the Collection
+    #[inline(always)]
+    pub fn collection_metadata(&mut self, collection_metadata: Option<Pubkey>) -> &mut Self {
+        self.collection_metadata = collection_metadata;
+        self
+    }
+    /// `[optional account]`
+    /// Master Edition Account of the Collection Token
+    #[inline(always)]
+    pub fn collection_master_edition(
+        &mut self,
+        collection_master_edition: Option<Pubkey>,
+    ) -> &mut Self {
+        self.collection_master_edition = collection_master_edition;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(&mut self, sysvar_instructions: Pubkey) -> &mut Self {
+        self.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(&mut self, account: instruction::AccountMeta) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(&mut self, accounts: &[instruction::AccountMeta]) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
+        let accounts = VerifyCollectionV1 {
+            authority: self
+                .authority
+                .ok_or(BuilderError::MissingField("authority"))?,
+            delegate_record: self.delegate_record,
+            metadata: self
+                .metadata
+                .ok_or(BuilderError::MissingField("metadata"))?,
+            collection_mint: self
+                .collection_mint
+                .ok_or(BuilderError::MissingField("collection_mint"))?,
+            collection_metadata: self.collection_metadata,
+            collection_master_edition: self.collection_master_edition,
+            system_program: self.system_program.unwrap_or(system_program_id()),
+            sysvar_instructions: self.sysvar_instructions.unwrap_or(sysvar_program_id()),
+        };
+
+        Ok(accounts.instruction_with_remaining_accounts(&self.__remaining_accounts))
+    }
+    /// Same as [`VerifyCollectionV1Builder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Accounts.
+pub struct UnverifyCollectionV1 {
+    /// Collection (or metadata if parent burned) update authority or delegate
+    pub authority: Pubkey,
+    /// Delegate record PDA
+    pub delegate_record: Option<Pubkey>,
+    /// Metadata account
+    pub metadata: Pubkey,
+    /// Mint of the Collection
+    pub collection_mint: Pubkey,
+    /// Metadata Account of the Collection
+    pub collection_metadata: Option<Pubkey>,
+    /// System program
+    pub system_program: Pubkey,
+    /// Instructions sysvar account
+    pub sysvar_instructions: Pubkey,
+}
+
+impl UnverifyCollectionV1 {
+    pub fn instruction(&self) -> instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[instruction::AccountMeta],
+    ) -> instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(instruction::AccountMeta::new_readonly(self.authority, true));
+        if let Some(delegate_record) = self.delegate_record {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                delegate_record,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new(self.metadata, false));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.collection_mint,
+            false,
+        ));
+        if let Some(collection_metadata) = self.collection_metadata {
+            accounts.push(instruction::AccountMeta::new(collection_metadata, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.sysvar_instructions,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&UnverifyCollectionV1InstructionData::new()).unwrap();
+
+        instruction::Instruction {
+            program_id: metadata_program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct UnverifyCollectionV1InstructionData {
+    discriminator: u8,
+    verification_args: VerificationArgs,
+}
+
+impl UnverifyCollectionV1InstructionData {
+    fn new() -> Self {
+        Self {
+            discriminator: 53,
+            verification_args: VerificationArgs::CollectionV1,
+        }
+    }
+}
+
+/// Instruction builder for `UnverifyCollectionV1`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[optional]` delegate_record
+///   2. `[writable]` metadata
+///   3. `[]` collection_mint
+///   4. `[writable, optional]` collection_metadata
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
+#[derive(Default)]
+pub struct UnverifyCollectionV1Builder {
+    authority: Option<Pubkey>,
+    delegate_record: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    collection_mint: Option<Pubkey>,
+    collection_metadata: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    sysvar_instructions: Option<Pubkey>,
+    __remaining_accounts: Vec<instruction::AccountMeta>,
+}
+
+impl UnverifyCollectionV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
+    /// Collection (or metadata if parent burned) update authority or delegate
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// Delegate record PDA
+    #[inline(always)]
+    pub fn delegate_record(&mut self, delegate_record: Option<Pubkey>) -> &mut Self {
+        self.delegate_record = delegate_record;
+        self
+    }
+    /// Metadata account
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// Mint of the Collection
+    #[inline(always)]
+    pub fn collection_mint(&mut self, collection_mint: Pubkey) -> &mut Self {
+        self.collection_mint = Some(collection_mint);
+        self
+    }
+    /// `[optional account]`
+    /// Metadata Account of the Collection
+    #[inline(always)]
+    pub fn collection_metadata(&mut self, collection_metadata: Option<Pubkey>) -> &mut Self {
+        self.collection_metadata = collection_metadata;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(&mut self, sysvar_instructions: Pubkey) -> &mut Self {
+        self.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(&mut self, account: instruction::AccountMeta) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(&mut self, accounts: &[instruction::AccountMeta]) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
+        let accounts = UnverifyCollectionV1 {
+            authority: self
+                .authority
+                .ok_or(BuilderError::MissingField("authority"))?,
+            delegate_record: self.delegate_record,
+            metadata: self
+                .metadata
+                .ok_or(BuilderError::MissingField("metadata"))?,
+            collection_mint: self
+                .collection_mint
+                .ok_or(BuilderError::MissingField("collection_mint"))?,
+            collection_metadata: self.collection_metadata,
+            system_program: self.system_program.unwrap_or(system_program_id()),
+            sysvar_instructions: self.sysvar_instructions.unwrap_or(sysvar_program_id()),
+        };
+
+        Ok(accounts.instruction_with_remaining_accounts(&self.__remaining_accounts))
+    }
+    /// Same as [`UnverifyCollectionV1Builder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Accounts.
+pub struct Print {
+    /// New Metadata key (pda of ['metadata', program id, mint id])
+    pub edition_metadata: Pubkey,
+    /// New Edition (pda of ['metadata', program id, mint id, 'edition'])
+    pub edition: Pubkey,
+    /// Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub edition_mint: (Pubkey, bool),
+    /// Owner of the token account of new token
+    pub edition_token_account_owner: Pubkey,
+    /// Token account of new token
+    pub edition_token_account: Pubkey,
+    /// Mint authority of new mint
+    pub edition_mint_authority: Pubkey,
+    /// Token record account
+    pub edition_token_record: Option<Pubkey>,
+    /// Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    pub master_edition: Pubkey,
+    /// Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
+    pub edition_marker_pda: Pubkey,
+    /// Payer
+    pub payer: Pubkey,
+    /// Owner of token account containing master token
+    pub master_token_account_owner: Pubkey,
+    /// Token account containing token from master metadata mint
+    pub master_token_account: Pubkey,
+    /// Master record metadata account
+    pub master_metadata: Pubkey,
+    /// The update authority of the master edition
+    pub update_authority: Pubkey,
+    /// Token program
+    pub spl_token_program: Pubkey,
+    /// SPL Associated Token Account program
+    pub spl_ata_program: Pubkey,
+    /// Instructions sysvar account
+    pub sysvar_instructions: Pubkey,
+    /// System program
+    pub system_program: Pubkey,
+}
+
+impl Print {
+    pub fn instruction(&self, args: PrintInstructionArgs) -> instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: PrintInstructionArgs,
+        remaining_accounts: &[instruction::AccountMeta],
+    ) -> instruction::Instruction {
+        let mut accounts = Vec::with_capacity(18 + remaining_accounts.len());
+        accounts.push(instruction::AccountMeta::new(self.edition_metadata, false));
+        accounts.push(instruction::AccountMeta::new(self.edition, false));
+        accounts.push(instruction::AccountMeta::new(
+            self.edition_mint.0,
+            self.edition_mint.1,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.edition_token_account_owner,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new(
+            self.edition_token_account,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.edition_mint_authority,
+            true,
+        ));
+        if let Some(edition_token_record) = self.edition_token_record {
+            accounts.push(instruction::AccountMeta::new(edition_token_record, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new(self.master_edition, false));
+        accounts.push(instruction::AccountMeta::new(
+            self.edition_marker_pda,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new(self.payer, true));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.master_token_account_owner,
+            true,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.master_token_account,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.master_metadata,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.update_authority,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.spl_token_program,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.spl_ata_program,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.sysvar_instructions,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&PrintInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        instruction::Instruction {
+            program_id: metadata_program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct PrintInstructionData {
+    discriminator: u8,
+}
+
+impl PrintInstructionData {
+    fn new() -> Self {
+        Self { discriminator: 55 }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct PrintInstructionArgs {
+    pub print_args: PrintArgs,
+}
+
+/// Instruction builder for `Print`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` edition_metadata
+///   1. `[writable]` edition
+///   2. `[writable, signer]` edition_mint
+///   3. `[]` edition_token_account_owner
+///   4. `[writable]` edition_token_account
+///   5. `[signer]` edition_mint_authority
+///   6. `[writable, optional]` edition_token_record
+///   7. `[writable]` master_edition
+///   8. `[writable]` edition_marker_pda
+///   9. `[writable, signer]` payer
+///   10. `[signer]` master_token_account_owner
+///   11. `[]` master_token_account
+///   12. `[]` master_metadata
+///   13. `[]` update_authority
+///   14. `[optional]` spl_token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   15. `[optional]` spl_ata_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   16. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
+///   17. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct PrintBuilder {
+    edition_metadata: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    edition_mint: Option<(Pubkey, bool)>,
+    edition_token_account_owner: Option<Pubkey>,
+    edition_token_account: Option<Pubkey>,
+    edition_mint_authority: Option<Pubkey>,
+    edition_token_record: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    edition_marker_pda: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    master_token_account_owner: Option<Pubkey>,
+    master_token_account: Option<Pubkey>,
+    master_metadata: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    spl_token_program: Option<Pubkey>,
+    spl_ata_program: Option<Pubkey>,
+    sysvar_instructions: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    print_args: Option<PrintArgs>,
+    __remaining_accounts: Vec<instruction::AccountMeta>,
+}
+
+impl PrintBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
+    /// New Metadata key (pda of ['metadata', program id, mint id])
+    #[inline(always)]
+    pub fn edition_metadata(&mut self, edition_metadata: Pubkey) -> &mut Self {
+        self.edition_metadata = Some(edition_metadata);
+        self
+    }
+    /// New Edition (pda of ['metadata', program id, mint id, 'edition'])
+    #[inline(always)]
+    pub fn edition(&mut self, edition: Pubkey) -> &mut Self {
+        self.edition = Some(edition);
+        self
+    }
+    /// Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    #[inline(always)]
+    pub fn edition_mint(&mut self, edition_mint: Pubkey, as_signer: bool) -> &mut Self {
+        self.edition_mint = Some((edition_mint, as_signer));
+        self
+    }
+    /// Owner of the token account of new token
+    #[inline(always)]
+    pub fn edition_token_account_owner(
+        &mut self,
+        edition_token_account_owner: Pubkey,
+    ) -> &mut Self {
+        self.edition_token_account_owner = Some(edition_token_account_owner);
+        self
+    }
+    /// Token account of new token
+    #[inline(always)]
+    pub fn edition_token_account(&mut self, edition_token_account: Pubkey) -> &mut Self {
+        self.edition_token_account = Some(edition_token_account);
+        self
+    }
+    /// Mint authority of new mint
+    #[inline(always)]
+    pub fn edition_mint_authority(&mut self, edition_mint_authority: Pubkey) -> &mut Self {
+        self.edition_mint_authority = Some(edition_mint_authority);
+        self
+    }
+    /// `[optional account]`
+    /// Token record account
+    #[inline(always)]
+    pub fn edition_token_record(&mut self, edition_token_record: Option<Pubkey>) -> &mut Self {
+        self.edition_token_record = edition_token_record;
+        self
+    }
+    /// Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    #[inline(always)]
+    pub fn master_edition(&mut self, master_edition: Pubkey) -> &mut Self {
+        self.master_edition = Some(master_edition);
+        self
+    }
+    /// Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
+    #[inline(always)]
+    pub fn edition_marker_pda(&mut self, edition_marker_pda: Pubkey) -> &mut Self {
+        self.edition_marker_pda = Some(edition_marker_pda);
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Owner of token account containing master token
+    #[inline(always)]
+    pub fn master_token_account_owner(&mut self, master_token_account_owner: Pubkey) -> &mut Self {
+        self.master_token_account_owner = Some(master_token_account_owner);
+        self
+    }
+    /// Token account containing token from master metadata mint
+    #[inline(always)]
+    pub fn master_token_account(&mut self, master_token_account: Pubkey) -> &mut Self {
+        self.master_token_account = Some(master_token_account);
+        self
+    }
+    /// Master record metadata account
+    #[inline(always)]
+    pub fn master_metadata(&mut self, master_metadata: Pubkey) -> &mut Self {
+        self.master_metadata = Some(master_metadata);
+        self
+    }
+    /// The update authority of the master edition
+    #[inline(always)]
+    pub fn update_authority(&mut self, update_authority: Pubkey) -> &mut Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// Token program
+    #[inline(always)]
+    pub fn spl_token_program(&mut self, spl_token_program: Pubkey) -> &mut Self {
+        self.spl_token_program = Some(spl_token_program);
+        self
+    }
+    /// `[optional account, default to 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL']`
+    /// SPL Associated Token Account program
+    #[inline(always)]
+    pub fn spl_ata_program(&mut self, spl_ata_program: Pubkey) -> &mut Self {
+        self.spl_ata_program = Some(spl_ata_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(&mut self, sysvar_instructions: Pubkey) -> &mut Self {
+        self.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn print_args(&mut self, print_args: PrintArgs) -> &mut Self {
+        self.print_args = Some(print_args);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(&mut self, account: instruction::AccountMeta) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(&mut self, accounts: &[instruction::AccountMeta]) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
+        let accounts = Print {
+            edition_metadata: self
+                .edition_metadata
+                .ok_or(BuilderError::MissingField("edition_metadata"))?,
+            edition: self.edition.ok_or(BuilderError::MissingField("edition"))?,
+            edition_mint: self
+                .edition_mint
+                .ok_or(BuilderError::MissingField("edition_mint"))?,
+            edition_token_account_owner: self
+                .edition_token_account_owner
+                .ok_or(BuilderError::MissingField("edition_token_account_owner"))?,
+            edition_token_account: self
+                .edition_token_account
+                .ok_or(BuilderError::MissingField("edition_token_account"))?,
+            edition_mint_authority: self
+                .edition_mint_authority
+                .ok_or(BuilderError::MissingField("edition_mint_authority"))?,
+            edition_token_record: self.edition_token_record,
+            master_edition: self
+                .master_edition
+                .ok_or(BuilderError::MissingField("master_edition"))?,
+            edition_marker_pda: self
+                .edition_marker_pda
+                .ok_or(BuilderError::MissingField("edition_marker_pda"))?,
+            payer: self.payer.ok_or(BuilderError::MissingField("payer"))?,
+            master_token_account_owner: self
+                .master_token_account_owner
+                .ok_or(BuilderError::MissingField("master_token_account_owner"))?,
+            master_token_account: self
+                .master_token_account
+                .ok_or(BuilderError::MissingField("master_token_account"))?,
+            master_metadata: self
+                .master_metadata
+                .ok_or(BuilderError::MissingField("master_metadata"))?,
+            update_authority: self
+                .update_authority
+                .ok_or(BuilderError::MissingField("update_authority"))?,
+            spl_token_program: self.spl_token_program.unwrap_or(token_program_id()),
+            spl_ata_program: self
+                .spl_ata_program
+                .unwrap_or(associated_account_program_id()),
+            sysvar_instructions: self.sysvar_instructions.unwrap_or(sysvar_program_id()),
+            system_program: self.system_program.unwrap_or(system_program_id()),
+        };
+        let args = PrintInstructionArgs {
+            print_args: self
+                .print_args
+                .clone()
+                .ok_or(BuilderError::MissingField("print_args"))?,
+        };
+
+        Ok(accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts))
+    }
+    /// Same as [`PrintBuilder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Accounts.
+pub struct UseAsset {
+    /// Token owner or delegate
+    pub authority: Pubkey,
+    /// Delegate record PDA
+    pub delegate_record: Option<Pubkey>,
+    /// Token account of mint
+    pub token: Option<Pubkey>,
+    /// Mint account
+    pub mint: Pubkey,
+    /// Metadata account
+    pub metadata: Pubkey,
+    /// Edition account
+    pub edition: Option<Pubkey>,
+    /// Payer
+    pub payer: Pubkey,
+    /// System program
+    pub system_program: Pubkey,
+    /// Instructions sysvar account
+    pub sysvar_instructions: Pubkey,
+    /// SPL Token Program
+    pub spl_token_program: Option<Pubkey>,
+    /// Token Authorization Rules Program
+    pub authorization_rules_program: Option<Pubkey>,
+    /// Token Authorization Rules account
+    pub authorization_rules: Option<Pubkey>,
+}
+
+impl UseAsset {
+    pub fn instruction(&self, args: UseAssetV1InstructionArgs) -> instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: UseAssetV1InstructionArgs,
+        remaining_accounts: &[instruction::AccountMeta],
+    ) -> instruction::Instruction {
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        accounts.push(instruction::AccountMeta::new_readonly(self.authority, true));
+        if let Some(delegate_record) = self.delegate_record {
+            accounts.push(instruction::AccountMeta::new(delegate_record, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(token) = self.token {
+            accounts.push(instruction::AccountMeta::new(token, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new_readonly(self.mint, false));
+        accounts.push(instruction::AccountMeta::new(self.metadata, false));
+        if let Some(edition) = self.edition {
+            accounts.push(instruction::AccountMeta::new_readonly(edition, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new(self.payer, true));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.sysvar_instructions,
+            false,
+        ));
+        if let Some(spl_token_program) = self.spl_token_program {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                spl_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(authorization_rules_program) = self.authorization_rules_program {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                authorization_rules_program,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(authorization_rules) = self.authorization_rules {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                authorization_rules,
+                false,
+            ));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&UseAssetV1InstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        instruction::Instruction {
+            program_id: metadata_program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct UseAssetV1InstructionData {
+    discriminator: u8,
+    use_v1_discriminator: u8,
+}
+
+impl UseAssetV1InstructionData {
+    fn new() -> Self {
+        Self {
+            discriminator: 51,
+            use_v1_discriminator: 0,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct UseAssetV1InstructionArgs {
+    pub authorization_data: Option<AuthorizationData>,
+}
+
+/// Instruction builder for `UseAsset`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable, optional]` delegate_record
+///   2. `[writable, optional]` token
+///   3. `[]` mint
+///   4. `[writable]` metadata
+///   5. `[optional]` edition
+///   6. `[writable, signer]` payer
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[optional]` sysvar_instructions (default to `Sysvar1nstructions1111111111111111111111111`)
+///   9. `[optional]` spl_token_program
+///   10. `[optional]` authorization_rules_program
+///   11. `[optional]` authorization_rules
+#[derive(Default)]
+pub struct UseAssetBuilder {
+    authority: Option<Pubkey>,
+    delegate_record: Option<Pubkey>,
+    token: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    sysvar_instructions: Option<Pubkey>,
+    spl_token_program: Option<Pubkey>,
+    authorization_rules_program: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    authorization_data: Option<AuthorizationData>,
+    __remaining_accounts: Vec<instruction::AccountMeta>,
+}
+
+impl UseAssetBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
+    /// Token owner or delegate
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// Delegate record PDA
+    #[inline(always)]
+    pub fn delegate_record(&mut self, delegate_record: Option<Pubkey>) -> &mut Self {
+        self.delegate_record = delegate_record;
+        self
+    }
+    /// `[optional account]`
+    /// Token account of mint
+    #[inline(always)]
+    pub fn token(&mut self, token: Option<Pubkey>) -> &mut Self {
+        self.token = token;
+        self
+    }
+    /// Mint account
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Metadata account
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// `[optional account]`
+    /// Edition account
+    #[inline(always)]
+    pub fn edition(&mut self, edition: Option<Pubkey>) -> &mut Self {
+        self.edition = edition;
+        self
+    }
+    /// Payer
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar account
+    #[inline(always)]
+    pub fn sysvar_instructions(&mut self, sysvar_instructions: Pubkey) -> &mut Self {
+        self.sysvar_instructions = Some(sysvar_instructions);
+        self
+    }
+    /// `[optional account]`
+    /// SPL Token Program
+    #[inline(always)]
+    pub fn spl_token_program(&mut self, spl_token_program: Option<Pubkey>) -> &mut Self {
+        self.spl_token_program = spl_token_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules Program
+    #[inline(always)]
+    pub fn authorization_rules_program(
+        &mut self,
+        authorization_rules_program: Option<Pubkey>,
+    ) -> &mut Self {
+        self.authorization_rules_program = authorization_rules_program;
+        self
+    }
+    /// `[optional account]`
+    /// Token Authorization Rules account
+    #[inline(always)]
+    pub fn authorization_rules(&mut self, authorization_rules: Option<Pubkey>) -> &mut Self {
+        self.authorization_rules = authorization_rules;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn authorization_data(&mut self, authorization_data: AuthorizationData) -> &mut Self {
+        self.authorization_data = Some(authorization_data);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(&mut self, account: instruction::AccountMeta) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(&mut self, accounts: &[instruction::AccountMeta]) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
+        let accounts = UseAsset {
+            authority: self
+                .authority
+                .ok_or(BuilderError::MissingField("authority"))?,
+            delegate_record: self.delegate_record,
+            token: self.token,
+            mint: self.mint.ok_or(BuilderError::MissingField("mint"))?,
+            metadata: self
+                .metadata
+                .ok_or(BuilderError::MissingField("metadata"))?,
+            edition: self.edition,
+            payer: self.payer.ok_or(BuilderError::MissingField("payer"))?,
+            system_program: self.system_program.unwrap_or(system_program_id()),
+            sysvar_instructions: self.sysvar_instructions.unwrap_or(sysvar_program_id()),
+            spl_token_program: self.spl_token_program,
+            authorization_rules_program: self.authorization_rules_program,
+            authorization_rules: self.authorization_rules,
+        };
+        let args = UseAssetV1InstructionArgs {
+            authorization_data: self.authorization_data.clone(),
+        };
+
+        Ok(accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts))
+    }
+    /// Same as [`UseAssetBuilder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Accounts.
+pub struct Resize {
+    /// The metadata account of the digital asset
+    pub metadata: Pubkey,
+    /// The master edition or edition account of the digital asset, an uninitialized account for fungible assets
+    pub edition: Pubkey,
+    /// Mint of token asset
+    pub mint: Pubkey,
+    /// The recipient of the excess rent and authority if the authority account is not present
+    pub payer: Pubkey,
+    /// Owner of the asset for (p)NFTs, or mint authority for fungible assets, if different from the payer
+    pub authority: Option<Pubkey>,
+    /// Token or Associated Token account
+    pub token: Option<Pubkey>,
+    /// System program
+    pub system_program: Pubkey,
+}
+
+impl Resize {
+    pub fn instruction(&self) -> instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[instruction::AccountMeta],
+    ) -> instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(instruction::AccountMeta::new(self.metadata, false));
+        accounts.push(instruction::AccountMeta::new(self.edition, false));
+        accounts.push(instruction::AccountMeta::new_readonly(self.mint, false));
+        accounts.push(instruction::AccountMeta::new(self.payer, true));
+        if let Some(authority) = self.authority {
+            accounts.push(instruction::AccountMeta::new_readonly(authority, true));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        if let Some(token) = self.token {
+            accounts.push(instruction::AccountMeta::new_readonly(token, false));
+        } else {
+            accounts.push(instruction::AccountMeta::new_readonly(
+                metadata_program_id(),
+                false,
+            ));
+        }
+        accounts.push(instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&ResizeInstructionData::new()).unwrap();
+
+        instruction::Instruction {
+            program_id: metadata_program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct ResizeInstructionData {
+    discriminator: u8,
+}
+
+impl ResizeInstructionData {
+    fn new() -> Self {
+        Self { discriminator: 56 }
+    }
+}
+
+/// Instruction builder for `Resize`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` metadata
+///   1. `[writable]` edition
+///   2. `[]` mint
+///   3. `[writable, signer]` payer
+///   4. `[signer, optional]` authority
+///   5. `[optional]` token
+///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct ResizeBuilder {
+    metadata: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    token: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    __remaining_accounts: Vec<instruction::AccountMeta>,
+}
+
+impl ResizeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
+    /// The metadata account of the digital asset
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// The master edition or edition account of the digital asset, an uninitialized account for fungible assets
+    #[inline(always)]
+    pub fn edition(&mut self, edition: Pubkey) -> &mut Self {
+        self.edition = Some(edition);
+        self
+    }
+    /// Mint of token asset
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// The recipient of the excess rent and authority if the authority account is not present
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// Owner of the asset for (p)NFTs, or mint authority for fungible assets, if different from the payer
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Option<Pubkey>) -> &mut Self {
+        self.authority = authority;
+        self
+    }
+    /// `[optional account]`
+    /// Token or Associated Token account
+    #[inline(always)]
+    pub fn token(&mut self, token: Option<Pubkey>) -> &mut Self {
+        self.token = token;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(&mut self, account: instruction::AccountMeta) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(&mut self, accounts: &[instruction::AccountMeta]) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
+        let accounts = Resize {
+            metadata: self
+                .metadata
+                .ok_or(BuilderError::MissingField("metadata"))?,
+            edition: self.edition.ok_or(BuilderError::MissingField("edition"))?,
+            mint: self.mint.ok_or(BuilderError::MissingField("mint"))?,
+            payer: self.payer.ok_or(BuilderError::MissingField("payer"))?,
+            authority: self.authority,
+            token: self.token,
+            system_program: self.system_program.unwrap_or(system_program_id()),
+        };
+
+        Ok(accounts.instruction_with_remaining_accounts(&self.__remaining_accounts))
+    }
+    /// Same as [`ResizeBuilder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+/// Accounts.
+pub struct Collect {
+    /// Authority to collect fees
+    pub authority: Pubkey,
+    /// The account to transfer collected fees to
+    pub recipient: Pubkey,
+    /// Accounts holding the accumulated fees
+    pub fee_accounts: Vec<Pubkey>,
+}
+
+impl Collect {
+    pub fn instruction(&self) -> instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[instruction::AccountMeta],
+    ) -> instruction::Instruction {
+        let mut accounts =
+            Vec::with_capacity(2 + self.fee_accounts.len() + remaining_accounts.len());
+        accounts.push(instruction::AccountMeta::new_readonly(self.authority, true));
+        accounts.push(instruction::AccountMeta::new(self.recipient, false));
+        for fee_account in self.fee_accounts.iter() {
+            accounts.push(instruction::AccountMeta::new(*fee_account, false));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&CollectInstructionData::new()).unwrap();
+
+        instruction::Instruction {
+            program_id: metadata_program_id(),
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct CollectInstructionData {
+    discriminator: u8,
+}
+
+impl CollectInstructionData {
+    fn new() -> Self {
+        Self { discriminator: 54 }
+    }
+}
+
+/// Instruction builder for `Collect`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` recipient
+///   2. ..2+N `[writable]` fee accounts
+#[derive(Default)]
+pub struct CollectBuilder {
+    authority: Option<Pubkey>,
+    recipient: Option<Pubkey>,
+    fee_accounts: Vec<Pubkey>,
+    __remaining_accounts: Vec<instruction::AccountMeta>,
+}
+
+impl CollectBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        let mut remaining_accounts = std::mem::take(&mut self.__remaining_accounts);
+        remaining_accounts.clear();
+        *self = Self {
+            __remaining_accounts: remaining_accounts,
+            ..Self::default()
+        };
+        self
+    }
+    /// Authority to collect fees
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// The account to transfer collected fees to
+    #[inline(always)]
+    pub fn recipient(&mut self, recipient: Pubkey) -> &mut Self {
+        self.recipient = Some(recipient);
+        self
+    }
+    /// Add an account holding accumulated fees.
+    #[inline(always)]
+    pub fn add_fee_account(&mut self, fee_account: Pubkey) -> &mut Self {
+        self.fee_accounts.push(fee_account);
+        self
+    }
+    /// Add accounts holding accumulated fees.
+    #[inline(always)]
+    pub fn add_fee_accounts(&mut self, fee_accounts: &[Pubkey]) -> &mut Self {
+        self.fee_accounts.extend_from_slice(fee_accounts);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(&mut self, account: instruction::AccountMeta) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(&mut self, accounts: &[instruction::AccountMeta]) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> Result<instruction::Instruction, BuilderError> {
+        let accounts = Collect {
+            authority: self
+                .authority
+                .ok_or(BuilderError::MissingField("authority"))?,
+            recipient: self
+                .recipient
+                .ok_or(BuilderError::MissingField("recipient"))?,
+            fee_accounts: self.fee_accounts.clone(),
+        };
+
+        Ok(accounts.instruction_with_remaining_accounts(&self.__remaining_accounts))
+    }
+    /// Same as [`CollectBuilder::instruction`] but panics if a required field is not set.
+    pub fn build_unchecked(&self) -> instruction::Instruction {
+        self.instruction().unwrap_or_else(|err| panic!("{}", err))
     }
 }
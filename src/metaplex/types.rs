@@ -1,14 +1,30 @@
 use crate::metaplex::derive_edition_pda;
 use crate::metaplex::derive_metadata_pda;
 use crate::metaplex::derive_token_record_pda;
+use crate::utils::is_on_curve;
 use ic_solana::types::Pubkey;
 // use borsh::BorshDeserialize;
 // use borsh::BorshSerialize;
 use borsh_derive::{BorshDeserialize, BorshSerialize};
+use core::fmt;
 use serde_derive::Deserialize;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
-#[derive(Deserialize)]
+pub const MAX_SELLER_FEE_BASIS_POINTS: u16 = 10_000;
+pub const MAX_CREATOR_LIMIT: usize = 5;
+/// Longest name, in bytes, following Metaplex conventions
+pub const MAX_NAME_LENGTH: usize = 32;
+/// Longest symbol, in bytes
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+/// Longest uri, in bytes
+pub const MAX_URI_LENGTH: usize = 200;
+
+#[derive(Clone, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+#[cfg_attr(feature = "candid", derive(candid::CandidType))]
 pub struct FungibleFields {
     pub name: String,
     pub symbol: String,
@@ -17,20 +33,16 @@ pub struct FungibleFields {
 
 impl From<FungibleFields> for DataV2 {
     fn from(value: FungibleFields) -> Self {
-        DataV2 {
-            name: value.name,
-            symbol: value.symbol,
-            uri: value.uri,
-            seller_fee_basis_points: 0,
-            creators: None,
-            collection: None,
-            uses: None,
-        }
+        DataV2::new_fungible(value.name, value.symbol, value.uri)
     }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "candid", derive(candid::CandidType))]
 pub struct DataV2 {
     pub name: String,
     pub symbol: String,
@@ -41,38 +53,114 @@ pub struct DataV2 {
     pub uses: Option<Uses>,
 }
 
+impl DataV2 {
+    /// Metadata for a fungible token: no royalties, creators, collection or uses.
+    pub fn new_fungible(name: String, symbol: String, uri: String) -> Self {
+        Self {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        }
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "candid", derive(candid::CandidType))]
 pub struct Collection {
     pub verified: bool,
-    // #[cfg_attr(
-    //     feature = "serde",
-    //     serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
-    // )]
     pub key: Pubkey,
 }
 
+impl Collection {
+    pub fn new_unverified(key: Pubkey) -> Self {
+        Self {
+            verified: false,
+            key,
+        }
+    }
+
+    pub fn new_verified(key: Pubkey) -> Self {
+        Self {
+            verified: true,
+            key,
+        }
+    }
+
+    pub fn mark_verified(&mut self) {
+        self.verified = true;
+    }
+
+    pub fn mark_unverified(&mut self) {
+        self.verified = false;
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub enum CollectionDetails {
     V1 { size: u64 },
     V2 { padding: [u8; 8] },
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "candid", derive(candid::CandidType))]
 pub struct Creator {
-    // #[cfg_attr(
-    //     feature = "serde",
-    //     serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
-    // )]
     pub address: Pubkey,
     pub verified: bool,
     pub share: u8,
 }
 
+impl Creator {
+    /// Creates a creator, checking that the share is a non-zero percentage
+    /// and that the address is a wallet (on-curve).
+    ///
+    /// PDA creators, such as program-owned royalty vaults, are valid on chain
+    /// but are off-curve, so they must be created with [`Creator::new_unchecked`].
+    pub fn new(address: Pubkey, share: u8, verified: bool) -> Result<Creator, CreatorError> {
+        if share == 0 {
+            return Err(CreatorError::ZeroShare);
+        }
+        if share > 100 {
+            return Err(CreatorError::InvalidShare(share));
+        }
+        if !is_on_curve(&address) {
+            return Err(CreatorError::AddressNotOnCurve(address.to_string()));
+        }
+        Ok(Self::new_unchecked(address, share, verified))
+    }
+
+    /// Creates a creator without any validation, e.g. for creators read back
+    /// from on-chain metadata or PDA creators.
+    pub fn new_unchecked(address: Pubkey, share: u8, verified: bool) -> Creator {
+        Creator {
+            address,
+            verified,
+            share,
+        }
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "candid", derive(candid::CandidType))]
 pub struct Uses {
     pub use_method: UseMethod,
     pub remaining: u64,
@@ -80,23 +168,76 @@ pub struct Uses {
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq, PartialOrd, Hash)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "candid", derive(candid::CandidType))]
 pub enum UseMethod {
     Burn,
     Multiple,
     Single,
 }
 
+impl TryFrom<u8> for UseMethod {
+    type Error = UnknownUseMethodError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Burn),
+            1 => Ok(Self::Multiple),
+            2 => Ok(Self::Single),
+            _ => Err(UnknownUseMethodError(value)),
+        }
+    }
+}
+
+impl Display for UseMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Burn => write!(f, "Burn"),
+            Self::Multiple => write!(f, "Multiple"),
+            Self::Single => write!(f, "Single"),
+        }
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub enum PrintSupply {
     Zero,
     Limited(u64),
     Unlimited,
 }
 
+impl PrintSupply {
+    pub fn is_unlimited(&self) -> bool {
+        matches!(self, Self::Unlimited)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        matches!(self, Self::Zero)
+    }
+
+    /// Maximum number of prints, `None` when unlimited
+    pub fn limit(&self) -> Option<u64> {
+        match self {
+            Self::Zero => Some(0),
+            Self::Limited(limit) => Some(*limit),
+            Self::Unlimited => None,
+        }
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq, PartialOrd, Hash)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "candid", derive(candid::CandidType))]
 pub enum TokenStandard {
     NonFungible,
     FungibleAsset,
@@ -106,8 +247,68 @@ pub enum TokenStandard {
     ProgrammableNonFungibleEdition,
 }
 
+impl Display for TokenStandard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonFungible => write!(f, "nonFungible"),
+            Self::FungibleAsset => write!(f, "fungibleAsset"),
+            Self::Fungible => write!(f, "fungible"),
+            Self::NonFungibleEdition => write!(f, "nonFungibleEdition"),
+            Self::ProgrammableNonFungible => write!(f, "programmableNonFungible"),
+            Self::ProgrammableNonFungibleEdition => write!(f, "programmableNonFungibleEdition"),
+        }
+    }
+}
+
+impl FromStr for TokenStandard {
+    type Err = TokenStandardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "nonfungible" => Ok(Self::NonFungible),
+            "fungibleasset" => Ok(Self::FungibleAsset),
+            "fungible" => Ok(Self::Fungible),
+            "nonfungibleedition" => Ok(Self::NonFungibleEdition),
+            "programmablenonfungible" => Ok(Self::ProgrammableNonFungible),
+            "programmablenonfungibleedition" => Ok(Self::ProgrammableNonFungibleEdition),
+            _ => Err(TokenStandardParseError(s.to_string())),
+        }
+    }
+}
+
+impl TokenStandard {
+    pub fn is_nft(&self) -> bool {
+        matches!(
+            self,
+            Self::NonFungible
+                | Self::NonFungibleEdition
+                | Self::ProgrammableNonFungible
+                | Self::ProgrammableNonFungibleEdition
+        )
+    }
+
+    pub fn is_fungible(&self) -> bool {
+        matches!(self, Self::Fungible | Self::FungibleAsset)
+    }
+
+    /// Whether creating an asset of this standard needs a master edition account
+    pub fn requires_master_edition(&self) -> bool {
+        matches!(self, Self::NonFungible | Self::ProgrammableNonFungible)
+    }
+
+    pub fn is_programmable(&self) -> bool {
+        matches!(
+            self,
+            Self::ProgrammableNonFungible | Self::ProgrammableNonFungibleEdition
+        )
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub enum CreateArgs {
     V1 {
         name: String,
@@ -127,8 +328,83 @@ pub enum CreateArgs {
     },
 }
 
+impl CreateArgs {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::V1 { name, .. } => name,
+        }
+    }
+
+    pub fn symbol(&self) -> &str {
+        match self {
+            Self::V1 { symbol, .. } => symbol,
+        }
+    }
+
+    pub fn uri(&self) -> &str {
+        match self {
+            Self::V1 { uri, .. } => uri,
+        }
+    }
+
+    pub fn decimals(&self) -> Option<u8> {
+        match self {
+            Self::V1 { decimals, .. } => *decimals,
+        }
+    }
+
+    pub fn token_standard(&self) -> &TokenStandard {
+        match self {
+            Self::V1 { token_standard, .. } => token_standard,
+        }
+    }
+
+    /// Checks the constraints the Token Metadata program enforces on create.
+    pub fn validate(&self) -> Result<(), MetaplexError> {
+        let Self::V1 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points,
+            creators,
+            token_standard,
+            decimals,
+            ..
+        } = self;
+        if name.len() > MAX_NAME_LENGTH {
+            return Err(MetaplexError::NameTooLong(name.len()));
+        }
+        if symbol.len() > MAX_SYMBOL_LENGTH {
+            return Err(MetaplexError::SymbolTooLong(symbol.len()));
+        }
+        if uri.len() > MAX_URI_LENGTH {
+            return Err(MetaplexError::UriTooLong(uri.len()));
+        }
+        if let Some(creators) = creators {
+            validate_creators(creators)?;
+        }
+        validate_seller_fee_basis_points(*seller_fee_basis_points)?;
+        if !token_standard.is_fungible() && decimals.is_some() {
+            return Err(MetaplexError::DecimalsNotAllowed(token_standard.clone()));
+        }
+        Ok(())
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub enum PrintArgs {
+    V1 { edition: u64 },
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub struct Data {
     pub name: String,
     pub symbol: String,
@@ -137,52 +413,271 @@ pub struct Data {
     pub creators: Option<Vec<Creator>>,
 }
 
+/// Drops `collection` and `uses`, which `Data` does not carry.
+impl From<DataV2> for Data {
+    fn from(value: DataV2) -> Self {
+        Data {
+            name: value.name,
+            symbol: value.symbol,
+            uri: value.uri,
+            seller_fee_basis_points: value.seller_fee_basis_points,
+            creators: value.creators,
+        }
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub enum CollectionToggle {
     None,
     Clear,
     Set(Collection),
 }
 
+impl CollectionToggle {
+    /// Sets the collection to `key`, unverified.
+    pub fn from_pubkey(key: Pubkey) -> Self {
+        Self::Set(Collection::new_unverified(key))
+    }
+
+    pub fn clear() -> Self {
+        Self::Clear
+    }
+
+    pub fn as_collection(&self) -> Option<&Collection> {
+        match self {
+            Self::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn is_set(&self) -> bool {
+        matches!(self, Self::Set(_))
+    }
+
+    pub fn is_clear(&self) -> bool {
+        matches!(self, Self::Clear)
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub enum CollectionDetailsToggle {
     None,
     Clear,
     Set(CollectionDetails),
 }
 
+impl CollectionDetailsToggle {
+    pub fn as_collection_details(&self) -> Option<&CollectionDetails> {
+        match self {
+            Self::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn is_set(&self) -> bool {
+        matches!(self, Self::Set(_))
+    }
+
+    pub fn is_clear(&self) -> bool {
+        matches!(self, Self::Clear)
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub enum UsesToggle {
     None,
     Clear,
     Set(Uses),
 }
 
+impl UsesToggle {
+    /// Sets fresh uses, with all `total` uses remaining.
+    pub fn from_uses(use_method: UseMethod, total: u64) -> Self {
+        Self::Set(Uses {
+            use_method,
+            remaining: total,
+            total,
+        })
+    }
+
+    pub fn clear() -> Self {
+        Self::Clear
+    }
+
+    pub fn as_uses(&self) -> Option<&Uses> {
+        match self {
+            Self::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn is_set(&self) -> bool {
+        matches!(self, Self::Set(_))
+    }
+
+    pub fn is_clear(&self) -> bool {
+        matches!(self, Self::Clear)
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub enum RuleSetToggle {
     None,
     Clear,
     Set(Pubkey),
 }
 
+impl RuleSetToggle {
+    pub fn as_rule_set(&self) -> Option<&Pubkey> {
+        match self {
+            Self::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn is_set(&self) -> bool {
+        matches!(self, Self::Set(_))
+    }
+
+    pub fn is_clear(&self) -> bool {
+        matches!(self, Self::Clear)
+    }
+}
+
+/// Variants are kept in the same order as the Token Metadata program since the
+/// borsh encoding relies on the variant index.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub enum DelegateArgs {
+    CollectionV1 {
+        authorization_data: Option<AuthorizationData>,
+    },
+    SaleV1 {
+        amount: u64,
+        authorization_data: Option<AuthorizationData>,
+    },
+    TransferV1 {
+        amount: u64,
+        authorization_data: Option<AuthorizationData>,
+    },
+    DataV1 {
+        authorization_data: Option<AuthorizationData>,
+    },
+    UtilityV1 {
+        amount: u64,
+        authorization_data: Option<AuthorizationData>,
+    },
+    StakingV1 {
+        amount: u64,
+        authorization_data: Option<AuthorizationData>,
+    },
+    StandardV1 {
+        amount: u64,
+    },
+    LockedTransferV1 {
+        amount: u64,
+        locked_address: Pubkey,
+        authorization_data: Option<AuthorizationData>,
+    },
+    ProgrammableConfigV1 {
+        authorization_data: Option<AuthorizationData>,
+    },
+    AuthorityItemV1 {
+        authorization_data: Option<AuthorizationData>,
+    },
+    DataItemV1 {
+        authorization_data: Option<AuthorizationData>,
+    },
+    CollectionItemV1 {
+        authorization_data: Option<AuthorizationData>,
+    },
+    ProgrammableConfigItemV1 {
+        authorization_data: Option<AuthorizationData>,
+    },
+    PrintDelegateV1 {
+        authorization_data: Option<AuthorizationData>,
+    },
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub enum VerificationArgs {
+    CreatorV1,
+    CollectionV1,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub struct AuthorizationData {
     pub payload: Payload,
 }
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+impl AuthorizationData {
+    pub fn new(payload: Payload) -> Self {
+        Self { payload }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub struct Payload {
     pub map: HashMap<String, PayloadType>,
 }
 
+impl Payload {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: PayloadType) -> &mut Self {
+        self.map.insert(key.into(), value);
+        self
+    }
+
+    pub fn with_pubkey(mut self, key: impl Into<String>, pubkey: Pubkey) -> Self {
+        self.insert(key, PayloadType::Pubkey(pubkey));
+        self
+    }
+
+    pub fn with_number(mut self, key: impl Into<String>, number: u64) -> Self {
+        self.insert(key, PayloadType::Number(number));
+        self
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub enum PayloadType {
     Pubkey(Pubkey),
     Seeds(SeedsVec),
@@ -191,31 +686,144 @@ pub enum PayloadType {
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub struct SeedsVec {
     pub seeds: Vec<Vec<u8>>,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
 pub struct ProofInfo {
     pub proof: Vec<[u8; 32]>,
 }
 
+impl ProofInfo {
+    /// Returns `true` if `proof` links `leaf` to `root`. Each level hashes the
+    /// current node and its sibling with SHA-256, smaller node first.
+    pub fn verify_leaf(&self, leaf: &[u8; 32], root: &[u8; 32]) -> bool {
+        let computed = self.proof.iter().fold(*leaf, |node, sibling| {
+            let (left, right) = if node <= *sibling {
+                (node, *sibling)
+            } else {
+                (*sibling, node)
+            };
+            Sha256::new()
+                .chain_update(left)
+                .chain_update(right)
+                .finalize()
+                .into()
+        });
+        computed == *root
+    }
+}
+
 pub type MintAddress = String;
 pub type NetworkError = String;
 use thiserror::Error;
 
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MetaplexError {
+    #[error("Creator shares must sum to 100, found {found}")]
+    InvalidCreatorShares { found: u16 },
+    #[error("At most 5 creators are allowed, found {0}")]
+    TooManyCreators(usize),
+    #[error("Seller fee basis points must be at most 10000, found {0}")]
+    InvalidSellerFee(u16),
+    #[error("{0} is not set")]
+    BuilderMissingField(&'static str),
+    #[error("Invalid pubkey string: {0}")]
+    InvalidPubkeyString(String),
+    #[error("Failed to derive program address")]
+    PdaDerivationFailed,
+    #[error("Unsupported token standard: {0}")]
+    UnsupportedTokenStandard(TokenStandard),
+    #[error("Name must be at most 32 bytes, found {0}")]
+    NameTooLong(usize),
+    #[error("Symbol must be at most 10 bytes, found {0}")]
+    SymbolTooLong(usize),
+    #[error("Uri must be at most 200 bytes, found {0}")]
+    UriTooLong(usize),
+    #[error("Decimals are not allowed for {0} assets")]
+    DecimalsNotAllowed(TokenStandard),
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuilderError {
+    #[error("{0} is not set")]
+    MissingField(&'static str),
+}
+
+impl From<BuilderError> for MetaplexError {
+    fn from(err: BuilderError) -> Self {
+        match err {
+            BuilderError::MissingField(field) => Self::BuilderMissingField(field),
+        }
+    }
+}
+
+/// The Token Metadata program rejects more than `MAX_CREATOR_LIMIT` creators,
+/// or creators whose shares do not sum to 100.
+pub fn validate_creators(creators: &[Creator]) -> Result<(), MetaplexError> {
+    if creators.len() > MAX_CREATOR_LIMIT {
+        return Err(MetaplexError::TooManyCreators(creators.len()));
+    }
+    // At most `MAX_CREATOR_LIMIT` shares of at most 255 each, so this cannot
+    // overflow
+    let found = creators
+        .iter()
+        .map(|creator| u16::from(creator.share))
+        .sum::<u16>();
+    if found != 100 {
+        return Err(MetaplexError::InvalidCreatorShares { found });
+    }
+    Ok(())
+}
+
+pub fn validate_seller_fee_basis_points(seller_fee_basis_points: u16) -> Result<(), MetaplexError> {
+    if seller_fee_basis_points > MAX_SELLER_FEE_BASIS_POINTS {
+        return Err(MetaplexError::InvalidSellerFee(seller_fee_basis_points));
+    }
+    Ok(())
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CreatorError {
+    #[error("Creator share must be at most 100, found {0}")]
+    InvalidShare(u8),
+    #[error("Creator share must not be zero")]
+    ZeroShare,
+    #[error("Creator address is not on the ed25519 curve: {0}")]
+    AddressNotOnCurve(String),
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("Unknown use method: {0}")]
+pub struct UnknownUseMethodError(pub u8);
+
+#[derive(Error, Debug)]
+#[error("Invalid token standard: {0}")]
+pub struct TokenStandardParseError(pub String);
+
 #[derive(Error, Debug)]
 pub enum UpdateError {
     #[error("Action failed with error: {1}")]
     UpdateFailed(MintAddress, NetworkError),
+    #[error(transparent)]
+    Metaplex(#[from] MetaplexError),
 }
 
 #[derive(Error, Debug)]
 pub enum ActionError {
     #[error("Action failed with error: {1}")]
     ActionFailed(MintAddress, NetworkError),
+    #[error(transparent)]
+    Metaplex(#[from] MetaplexError),
 }
 
 pub struct Asset {
@@ -235,6 +843,12 @@ impl Asset {
         }
     }
 
+    pub fn new_with_edition(mint: Pubkey) -> Self {
+        let mut asset = Self::new(mint);
+        asset.add_edition();
+        asset
+    }
+
     pub fn add_edition(&mut self) {
         self.edition = Some(derive_edition_pda(&self.mint));
     }
@@ -243,6 +857,10 @@ impl Asset {
         derive_token_record_pda(&self.mint, token)
     }
 
+    pub fn token_record_for(&self, token: &Pubkey) -> Pubkey {
+        self.get_token_record(token)
+    }
+
     // pub fn get_metadata(&self, client: &RpcClient) -> Result<Metadata, DecodeError> {
     //     decode_metadata(client, &self.metadata)
     // }
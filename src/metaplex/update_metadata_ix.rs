@@ -1,7 +1,7 @@
 use crate::metaplex::types::Asset;
 use crate::metaplex::types::{
-    AuthorizationData, CollectionDetailsToggle, CollectionToggle, Creator, Data, RuleSetToggle,
-    UsesToggle,
+    validate_seller_fee_basis_points, AuthorizationData, BuilderError, CollectionDetailsToggle,
+    CollectionToggle, Creator, Data, MetaplexError, RuleSetToggle, UsesToggle,
 };
 use ic_solana::types::Instruction;
 
@@ -17,6 +17,10 @@ pub struct UpdateMetaArgs {
     pub uri: String,
     pub seller_fee_basis_points: u16,
     pub creators: Option<Vec<Creator>>,
+    /// Setting this to `false` freezes the metadata permanently.
+    pub is_mutable: Option<bool>,
+    pub primary_sale_happened: Option<bool>,
+    pub new_update_authority: Option<Pubkey>,
     // pub priority: Priority,
 }
 // Wrapper type for the UpdateV1InstructionArgs type from mpl-token-metadata since it doesn't have a `default` implementation.
@@ -77,6 +81,121 @@ impl From<V1UpdateArgs> for UpdateV1InstructionArgs {
     }
 }
 
+/// Builder for [`V1UpdateArgs`].
+///
+/// `data` is only sent when one of its fields is set. Since the program
+/// replaces the whole `Data` struct, `name`, `symbol` and `uri` are then
+/// required; `seller_fee_basis_points` defaults to 0 and `creators` to none.
+#[derive(Default)]
+pub struct V1UpdateArgsBuilder {
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+    seller_fee_basis_points: Option<u16>,
+    creators: Option<Vec<Creator>>,
+    args: V1UpdateArgs,
+}
+
+impl V1UpdateArgsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Clears every field so the builder can be reused.
+    pub fn reset(&mut self) -> &mut Self {
+        *self = Self::default();
+        self
+    }
+    #[inline(always)]
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+    #[inline(always)]
+    pub fn symbol(&mut self, symbol: String) -> &mut Self {
+        self.symbol = Some(symbol);
+        self
+    }
+    #[inline(always)]
+    pub fn uri(&mut self, uri: String) -> &mut Self {
+        self.uri = Some(uri);
+        self
+    }
+    #[inline(always)]
+    pub fn seller_fee_basis_points(&mut self, seller_fee_basis_points: u16) -> &mut Self {
+        self.seller_fee_basis_points = Some(seller_fee_basis_points);
+        self
+    }
+    #[inline(always)]
+    pub fn creators(&mut self, creators: Vec<Creator>) -> &mut Self {
+        self.creators = Some(creators);
+        self
+    }
+    #[inline(always)]
+    pub fn new_update_authority(&mut self, new_update_authority: Pubkey) -> &mut Self {
+        self.args.new_update_authority = Some(new_update_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn primary_sale_happened(&mut self, primary_sale_happened: bool) -> &mut Self {
+        self.args.primary_sale_happened = Some(primary_sale_happened);
+        self
+    }
+    #[inline(always)]
+    pub fn is_mutable(&mut self, is_mutable: bool) -> &mut Self {
+        self.args.is_mutable = Some(is_mutable);
+        self
+    }
+    #[inline(always)]
+    pub fn collection(&mut self, collection: CollectionToggle) -> &mut Self {
+        self.args.collection = collection;
+        self
+    }
+    #[inline(always)]
+    pub fn collection_details(&mut self, collection_details: CollectionDetailsToggle) -> &mut Self {
+        self.args.collection_details = collection_details;
+        self
+    }
+    #[inline(always)]
+    pub fn uses(&mut self, uses: UsesToggle) -> &mut Self {
+        self.args.uses = uses;
+        self
+    }
+    #[inline(always)]
+    pub fn rule_set(&mut self, rule_set: RuleSetToggle) -> &mut Self {
+        self.args.rule_set = rule_set;
+        self
+    }
+    #[inline(always)]
+    pub fn authorization_data(&mut self, authorization_data: AuthorizationData) -> &mut Self {
+        self.args.authorization_data = Some(authorization_data);
+        self
+    }
+    pub fn build(&self) -> Result<V1UpdateArgs, BuilderError> {
+        let mut args = self.args.clone();
+        let has_data = self.name.is_some()
+            || self.symbol.is_some()
+            || self.uri.is_some()
+            || self.seller_fee_basis_points.is_some()
+            || self.creators.is_some();
+        if has_data {
+            args.data = Some(Data {
+                name: self
+                    .name
+                    .clone()
+                    .ok_or(BuilderError::MissingField("name"))?,
+                symbol: self
+                    .symbol
+                    .clone()
+                    .ok_or(BuilderError::MissingField("symbol"))?,
+                uri: self.uri.clone().ok_or(BuilderError::MissingField("uri"))?,
+                seller_fee_basis_points: self.seller_fee_basis_points.unwrap_or(0),
+                creators: self.creators.clone(),
+            });
+        }
+        Ok(args)
+    }
+}
+
 pub enum UpdateAssetArgs {
     V1 {
         payer: Option<Pubkey>,
@@ -89,10 +208,12 @@ pub enum UpdateAssetArgs {
     },
 }
 
-pub fn update_asset_v1_ix(args: UpdateMetaArgs) -> Instruction {
+pub fn update_asset_v1_ix(args: UpdateMetaArgs) -> Result<Instruction, MetaplexError> {
     // let current_md = decode_metadata_from_mint(&args.client, args.mint_account.clone())
     //     .map_err(|e| ActionError::ActionFailed(args.mint_account.to_string(), e.to_string()))?;
 
+    validate_seller_fee_basis_points(args.seller_fee_basis_points)?;
+
     // Token Metadata UpdateArgs enum.
     let mut update_args = V1UpdateArgs::default();
 
@@ -105,6 +226,9 @@ pub fn update_asset_v1_ix(args: UpdateMetaArgs) -> Instruction {
     };
 
     update_args.data = Some(data);
+    update_args.is_mutable = args.is_mutable;
+    update_args.primary_sale_happened = args.primary_sale_happened;
+    update_args.new_update_authority = args.new_update_authority;
 
     // Metaboss UpdateAssetArgs enum.
     // let update_args = UpdateAssetArgs::V1 {
@@ -134,5 +258,5 @@ pub fn update_asset_v1_ix(args: UpdateMetaArgs) -> Instruction {
     }
     .instruction(update_args.into());
 
-    update_ix
+    Ok(update_ix)
 }
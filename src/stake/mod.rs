@@ -0,0 +1,185 @@
+use crate::token::constants::stake_program_id;
+use crate::utils;
+use ic_solana::types::{AccountMeta, Instruction, Pubkey};
+use serde::{Deserialize, Serialize};
+
+pub type UnixTimestamp = i64;
+pub type Epoch = u64;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Authorized {
+    pub staker: Pubkey,
+    pub withdrawer: Pubkey,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lockup {
+    /// UnixTimestamp at which this stake will allow withdrawal, unless the
+    ///   transaction is signed by the custodian
+    pub unix_timestamp: UnixTimestamp,
+    /// epoch height at which this stake will allow withdrawal, unless the
+    ///   transaction is signed by the custodian
+    pub epoch: Epoch,
+    /// custodian signature on a transaction exempts the operation from
+    ///  lockup constraints
+    pub custodian: Pubkey,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StakeAuthorize {
+    Staker,
+    Withdrawer,
+}
+
+/// Instructions supported by the Stake program. Only the leading variants are
+/// mirrored; their order must match the program since bincode encodes the
+/// variant index.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum StakeInstruction {
+    /// Initialize a stake with lockup and authorization information
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Uninitialized stake account
+    ///   1. `[]` Rent sysvar
+    Initialize(Authorized, Lockup),
+
+    /// Authorize a key to manage stake or withdrawal
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Stake account to be updated
+    ///   1. `[]` Clock sysvar
+    ///   2. `[SIGNER]` The stake or withdraw authority
+    ///   3. Optional: `[SIGNER]` Lockup authority, if updating StakeAuthorize::Withdrawer before
+    ///      lockup expiration
+    Authorize(Pubkey, StakeAuthorize),
+
+    /// Delegate a stake to a particular vote account
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Initialized stake account to be delegated
+    ///   1. `[]` Vote account to which this stake will be delegated
+    ///   2. `[]` Clock sysvar
+    ///   3. `[]` Stake history sysvar that carries stake warmup/cooldown history
+    ///   4. `[]` Address of config account that carries stake config
+    ///   5. `[SIGNER]` Stake authority
+    DelegateStake,
+
+    /// Split u64 tokens and stake off a stake account into another stake account.
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Stake account to be split; must be in the Initialized or Stake state
+    ///   1. `[WRITE]` Uninitialized stake account that will take the split-off amount
+    ///   2. `[SIGNER]` Stake authority
+    Split(u64),
+
+    /// Withdraw unstaked lamports from the stake account
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Stake account from which to withdraw
+    ///   1. `[WRITE]` Recipient account
+    ///   2. `[]` Clock sysvar
+    ///   3. `[]` Stake history sysvar that carries stake warmup/cooldown history
+    ///   4. `[SIGNER]` Withdraw authority
+    ///   5. Optional: `[SIGNER]` Lockup authority, if before lockup expiration
+    ///
+    /// The u64 is the portion of the stake account balance to be withdrawn,
+    ///    must be `<= StakeAccount.lamports - staked_lamports`.
+    Withdraw(u64),
+
+    /// Deactivates the stake in the account
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Delegated stake account
+    ///   1. `[]` Clock sysvar
+    ///   2. `[SIGNER]` Stake authority
+    Deactivate,
+}
+
+pub fn stake_initialize(
+    stake_pubkey: &Pubkey,
+    staker: &Pubkey,
+    withdrawer: &Pubkey,
+    lockup: &Lockup,
+    rent_sysvar: &Pubkey,
+) -> Instruction {
+    utils::new_with_bincode(
+        stake_program_id(),
+        &StakeInstruction::Initialize(
+            Authorized {
+                staker: *staker,
+                withdrawer: *withdrawer,
+            },
+            *lockup,
+        ),
+        vec![
+            AccountMeta::new(*stake_pubkey, false),
+            AccountMeta::new_readonly(*rent_sysvar, false),
+        ],
+    )
+}
+
+pub fn stake_delegate(
+    stake_pubkey: &Pubkey,
+    vote_pubkey: &Pubkey,
+    clock_sysvar: &Pubkey,
+    stake_history_sysvar: &Pubkey,
+    config_pubkey: &Pubkey,
+    authorized_pubkey: &Pubkey,
+) -> Instruction {
+    utils::new_with_bincode(
+        stake_program_id(),
+        &StakeInstruction::DelegateStake,
+        vec![
+            AccountMeta::new(*stake_pubkey, false),
+            AccountMeta::new_readonly(*vote_pubkey, false),
+            AccountMeta::new_readonly(*clock_sysvar, false),
+            AccountMeta::new_readonly(*stake_history_sysvar, false),
+            AccountMeta::new_readonly(*config_pubkey, false),
+            AccountMeta::new_readonly(*authorized_pubkey, true),
+        ],
+    )
+}
+
+pub fn stake_deactivate(
+    stake_pubkey: &Pubkey,
+    clock_sysvar: &Pubkey,
+    authorized_pubkey: &Pubkey,
+) -> Instruction {
+    utils::new_with_bincode(
+        stake_program_id(),
+        &StakeInstruction::Deactivate,
+        vec![
+            AccountMeta::new(*stake_pubkey, false),
+            AccountMeta::new_readonly(*clock_sysvar, false),
+            AccountMeta::new_readonly(*authorized_pubkey, true),
+        ],
+    )
+}
+
+pub fn stake_withdraw(
+    stake_pubkey: &Pubkey,
+    to_pubkey: &Pubkey,
+    clock_sysvar: &Pubkey,
+    stake_history_sysvar: &Pubkey,
+    withdrawer_pubkey: &Pubkey,
+    custodian_pubkey: Option<&Pubkey>,
+    lamports: u64,
+) -> Instruction {
+    let mut account_metas = vec![
+        AccountMeta::new(*stake_pubkey, false),
+        AccountMeta::new(*to_pubkey, false),
+        AccountMeta::new_readonly(*clock_sysvar, false),
+        AccountMeta::new_readonly(*stake_history_sysvar, false),
+        AccountMeta::new_readonly(*withdrawer_pubkey, true),
+    ];
+
+    if let Some(custodian_pubkey) = custodian_pubkey {
+        account_metas.push(AccountMeta::new_readonly(*custodian_pubkey, true));
+    }
+
+    utils::new_with_bincode(
+        stake_program_id(),
+        &StakeInstruction::Withdraw(lamports),
+        account_metas,
+    )
+}
@@ -1,4 +1,6 @@
-use crate::token::constants::{associated_account_program_id, system_program_id};
+use crate::token::constants::{
+    associated_account_program_id, system_program_id, token22_program_id, token_program_id,
+};
 use ic_solana::types::{AccountMeta, Instruction, Pubkey};
 use borsh_derive::{BorshDeserialize, BorshSerialize};
 
@@ -107,6 +109,64 @@ pub fn create_associated_token_account_idempotent(
     )
 }
 
+/// Creates CreateIdempotent instruction for a Token-2022 mint
+pub fn create_associated_token_account_idempotent_token22(
+    funding_address: &Pubkey,
+    wallet_address: &Pubkey,
+    token_mint_address: &Pubkey,
+) -> Instruction {
+    create_associated_token_account_idempotent(
+        funding_address,
+        wallet_address,
+        token_mint_address,
+        &token22_program_id(),
+    )
+}
+
+/// Creates RecoverNested instruction: transfers the tokens held by the
+/// associated token account of `owner_token_mint_address`'s ATA to the
+/// wallet's own ATA, then closes it.
+///
+/// The associated token account program currently requires both token
+/// program ids to be the same program.
+pub fn recover_nested_associated_token_account(
+    wallet_address: &Pubkey,
+    owner_token_mint_address: &Pubkey,
+    nested_token_mint_address: &Pubkey,
+    owner_token_program_id: &Pubkey,
+    nested_token_program_id: &Pubkey,
+) -> Instruction {
+    let owner_associated_account_address = get_associated_token_address_with_program_id(
+        wallet_address,
+        owner_token_mint_address,
+        owner_token_program_id,
+    );
+    let destination_associated_account_address = get_associated_token_address_with_program_id(
+        wallet_address,
+        nested_token_mint_address,
+        nested_token_program_id,
+    );
+    let nested_associated_account_address = get_associated_token_address_with_program_id(
+        &owner_associated_account_address,
+        nested_token_mint_address,
+        nested_token_program_id,
+    );
+
+    Instruction {
+        program_id: associated_account_program_id(),
+        accounts: vec![
+            AccountMeta::new(nested_associated_account_address, false),
+            AccountMeta::new_readonly(*nested_token_mint_address, false),
+            AccountMeta::new(destination_associated_account_address, false),
+            AccountMeta::new_readonly(owner_associated_account_address, false),
+            AccountMeta::new_readonly(*owner_token_mint_address, false),
+            AccountMeta::new(*wallet_address, true),
+            AccountMeta::new_readonly(*nested_token_program_id, false),
+        ],
+        data: borsh::to_vec(&AssociatedTokenAccountInstruction::RecoverNested).unwrap(),
+    }
+}
+
 /// Derives the associated token account address for the given wallet address,
 /// token mint and token program id
 pub fn get_associated_token_address_with_program_id(
@@ -123,7 +183,80 @@ pub fn get_associated_token_address_with_program_id(
     .0
 }
 
-pub(crate) fn get_associated_token_address_and_bump_seed(
+/// Derives the associated token account address for the given wallet address
+/// and token mint, using the SPL Token program
+pub fn get_associated_token_address(
+    wallet_address: &Pubkey,
+    token_mint_address: &Pubkey,
+) -> Pubkey {
+    get_associated_token_address_with_program_id(
+        wallet_address,
+        token_mint_address,
+        &token_program_id(),
+    )
+}
+
+/// Derives the associated token account address for the given wallet address
+/// and token mint, using the Token-2022 program
+pub fn get_associated_token_address_token22(
+    wallet_address: &Pubkey,
+    token_mint_address: &Pubkey,
+) -> Pubkey {
+    get_associated_token_address_with_program_id(
+        wallet_address,
+        token_mint_address,
+        &token22_program_id(),
+    )
+}
+
+/// Derives the associated token account addresses of `wallet_address` for
+/// each of `token_mint_addresses`, in order
+pub fn derive_associated_token_addresses_batch(
+    wallet_address: &Pubkey,
+    token_mint_addresses: &[Pubkey],
+    token_program_id: &Pubkey,
+) -> Vec<Pubkey> {
+    let program_id = associated_account_program_id();
+    token_mint_addresses
+        .iter()
+        .map(|token_mint_address| {
+            get_associated_token_address_and_bump_seed_internal(
+                wallet_address,
+                token_mint_address,
+                &program_id,
+                token_program_id,
+            )
+            .0
+        })
+        .collect()
+}
+
+/// Derives the associated token account addresses of each of
+/// `wallet_addresses` for `token_mint_address`, in order
+pub fn derive_associated_token_addresses_for_wallets(
+    wallet_addresses: &[Pubkey],
+    token_mint_address: &Pubkey,
+    token_program_id: &Pubkey,
+) -> Vec<Pubkey> {
+    let program_id = associated_account_program_id();
+    wallet_addresses
+        .iter()
+        .map(|wallet_address| {
+            get_associated_token_address_and_bump_seed_internal(
+                wallet_address,
+                token_mint_address,
+                &program_id,
+                token_program_id,
+            )
+            .0
+        })
+        .collect()
+}
+
+/// Derives the associated token account address and its bump seed for the
+/// given wallet address, token mint, associated token account program id
+/// and token program id
+pub fn get_associated_token_address_and_bump_seed(
     wallet_address: &Pubkey,
     token_mint_address: &Pubkey,
     program_id: &Pubkey,
@@ -152,3 +285,49 @@ fn get_associated_token_address_and_bump_seed_internal(
         program_id,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn wallet() -> Pubkey {
+        Pubkey::from_str("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM").unwrap()
+    }
+
+    fn mint() -> Pubkey {
+        Pubkey::from_str("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU").unwrap()
+    }
+
+    #[test]
+    fn derives_token_program_address_and_bump() {
+        let (address, bump) = get_associated_token_address_and_bump_seed(
+            &wallet(),
+            &mint(),
+            &associated_account_program_id(),
+            &token_program_id(),
+        );
+
+        assert_eq!(
+            address,
+            Pubkey::from_str("HwpBSwuyVKJi7d9kqqNexc54MS9i4BEDKDVDLeUVjZm8").unwrap()
+        );
+        assert_eq!(bump, 253);
+    }
+
+    #[test]
+    fn derives_token22_program_address_and_bump() {
+        let (address, bump) = get_associated_token_address_and_bump_seed(
+            &wallet(),
+            &mint(),
+            &associated_account_program_id(),
+            &token22_program_id(),
+        );
+
+        assert_eq!(
+            address,
+            Pubkey::from_str("4pBVkj9faUCfkHwLnZ6CHNKfbMuBTBrwiKqVZJmurE9E").unwrap()
+        );
+        assert_eq!(bump, 255);
+    }
+}
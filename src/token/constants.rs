@@ -3,27 +3,33 @@ use serde_bytes::ByteBuf;
 use std::str::FromStr;
 
 pub fn system_program_id() -> Pubkey {
-    Pubkey::from_str("11111111111111111111111111111111").unwrap()
+    Pubkey::from_str("11111111111111111111111111111111")
+        .expect("system program id is a valid base58 pubkey")
 }
 
 pub fn compute_budget_id() -> Pubkey {
-    Pubkey::from_str("ComputeBudget111111111111111111111111111111").unwrap()
+    Pubkey::from_str("ComputeBudget111111111111111111111111111111")
+        .expect("compute budget program id is a valid base58 pubkey")
 }
 
 pub fn sysvar_program_id() -> Pubkey {
-    Pubkey::from_str("Sysvar1nstructions1111111111111111111111111").unwrap()
+    Pubkey::from_str("Sysvar1nstructions1111111111111111111111111")
+        .expect("instructions sysvar id is a valid base58 pubkey")
 }
 
 pub fn token_program_id() -> Pubkey {
-    Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap()
+    Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+        .expect("token program id is a valid base58 pubkey")
 }
 
 pub fn token22_program_id() -> Pubkey {
-    Pubkey::from_str("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb").unwrap()
+    Pubkey::from_str("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb")
+        .expect("token-2022 program id is a valid base58 pubkey")
 }
 
 pub fn associated_account_program_id() -> Pubkey {
-    Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap()
+    Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+        .expect("associated token account program id is a valid base58 pubkey")
 }
 
 pub fn route_signer_derive_path() -> Vec<ByteBuf> {
@@ -31,6 +37,37 @@ pub fn route_signer_derive_path() -> Vec<ByteBuf> {
 }
 
 pub fn memo_program_id() -> Pubkey {
-    Pubkey::from_str("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr").unwrap()
+    Pubkey::from_str("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr")
+        .expect("memo program id is a valid base58 pubkey")
 }
 
+pub fn address_lookup_table_program_id() -> Pubkey {
+    Pubkey::from_str("AddressLookupTab1e1111111111111111111111111")
+        .expect("address lookup table program id is a valid base58 pubkey")
+}
+
+pub fn stake_program_id() -> Pubkey {
+    Pubkey::from_str("Stake11111111111111111111111111111111111111")
+        .expect("stake program id is a valid base58 pubkey")
+}
+
+pub fn bpf_loader_upgradeable_program_id() -> Pubkey {
+    Pubkey::from_str("BPFLoaderUpgradeab1e11111111111111111111111")
+        .expect("bpf loader upgradeable program id is a valid base58 pubkey")
+}
+
+pub fn is_system_program(pubkey: &Pubkey) -> bool {
+    *pubkey == system_program_id()
+}
+
+pub fn is_token_program(pubkey: &Pubkey) -> bool {
+    *pubkey == token_program_id()
+}
+
+pub fn is_token22_program(pubkey: &Pubkey) -> bool {
+    *pubkey == token22_program_id()
+}
+
+pub fn is_memo_program(pubkey: &Pubkey) -> bool {
+    *pubkey == memo_program_id()
+}
@@ -1,5 +1,9 @@
 use std::str::FromStr;
 
+use crate::memo::build_memo;
+use crate::token::constants::system_program_id;
+use crate::token::program_error::ProgramError;
+use crate::utils;
 use ic_solana::types::{AccountMeta, Instruction, Pubkey};
 use num_derive::{FromPrimitive, ToPrimitive};
 use num_traits::FromPrimitive;
@@ -7,6 +11,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 pub const SYSTEM_PROGRAM_ID: &[u8; 32] = b"11111111111111111111111111111111";
 pub const SYSVAR_ID: &str = "SysvarRent111111111111111111111111111111111";
+pub const RECENT_BLOCKHASHES_SYSVAR_ID: &str = "SysvarRecentB1ockHashes11111111111111111111";
 
 pub trait DecodeError<E> {
     fn decode_custom_error_to_enum(custom: u32) -> Option<E>
@@ -56,6 +61,38 @@ pub const MAX_PERMITTED_DATA_LENGTH: u64 = 10 * 1024 * 1024;
 pub const MAX_PERMITTED_ACCOUNTS_DATA_ALLOCATIONS_PER_TRANSACTION: i64 =
     MAX_PERMITTED_DATA_LENGTH as i64 * 2;
 
+/// Account storage overhead for calculation of base rent, in bytes.
+pub const ACCOUNT_STORAGE_OVERHEAD: u64 = 128;
+
+/// Default rental rate in lamports/byte-year.
+pub const DEFAULT_LAMPORTS_PER_BYTE_YEAR: u64 = 3_480;
+
+/// Default amount of time (in years) the balance has to include rent for the
+/// account to be rent exempt.
+pub const DEFAULT_EXEMPTION_THRESHOLD: u64 = 2;
+
+/// Minimum balance for an account of `data_len` bytes to be rent exempt,
+/// assuming the default rent parameters.
+pub fn minimum_balance_for_rent_exemption(data_len: u64) -> Result<u64, ProgramError> {
+    minimum_balance_for_rent_exemption_with_rate(data_len, DEFAULT_LAMPORTS_PER_BYTE_YEAR)
+}
+
+/// Minimum balance for an account of `data_len` bytes to be rent exempt at
+/// `lamports_per_byte_year`, with the default exemption threshold.
+///
+/// Fails with [`ProgramError::ArithmeticOverflow`] if the balance does not
+/// fit in a `u64`.
+pub fn minimum_balance_for_rent_exemption_with_rate(
+    data_len: u64,
+    lamports_per_byte_year: u64,
+) -> Result<u64, ProgramError> {
+    ACCOUNT_STORAGE_OVERHEAD
+        .checked_add(data_len)
+        .and_then(|bytes| bytes.checked_mul(lamports_per_byte_year))
+        .and_then(|lamports| lamports.checked_mul(DEFAULT_EXEMPTION_THRESHOLD))
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
 /// An instruction to the system program.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum SystemInstruction {
@@ -230,6 +267,13 @@ pub enum SystemInstruction {
     UpgradeNonceAccount,
 }
 
+impl SystemInstruction {
+    /// Deserialize bincode encoded system program instruction data.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        bincode::deserialize(data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
 /// Create an account.
 ///
 /// This function produces an [`Instruction`] which must be submitted in a
@@ -318,7 +362,7 @@ pub fn create_account(
         AccountMeta::new(*from_pubkey, true),
         AccountMeta::new(*to_pubkey, true),
     ];
-    Instruction::new_with_bincode(
+    utils::new_with_bincode(
         Pubkey::from_str("11111111111111111111111111111111").unwrap(),
         &SystemInstruction::CreateAccount {
             lamports,
@@ -346,7 +390,7 @@ pub fn create_account_with_seed(
         AccountMeta::new_readonly(*base, true),
     ];
 
-    Instruction::new_with_bincode(
+    utils::new_with_bincode(
         Pubkey::from_str("11111111111111111111111111111111").unwrap(),
         &SystemInstruction::CreateAccountWithSeed {
             base: *base,
@@ -542,7 +586,7 @@ pub fn create_account_with_seed(
 /// ```
 pub fn assign(pubkey: &Pubkey, owner: &Pubkey) -> Instruction {
     let account_metas = vec![AccountMeta::new(*pubkey, true)];
-    Instruction::new_with_bincode(
+    utils::new_with_bincode(
         Pubkey::from_str("11111111111111111111111111111111").unwrap(),
         &SystemInstruction::Assign { owner: *owner },
         account_metas,
@@ -559,7 +603,7 @@ pub fn assign_with_seed(
         AccountMeta::new(*address, false),
         AccountMeta::new_readonly(*base, true),
     ];
-    Instruction::new_with_bincode(
+    utils::new_with_bincode(
         Pubkey::from_str("11111111111111111111111111111111").unwrap(),
         &SystemInstruction::AssignWithSeed {
             base: *base,
@@ -756,7 +800,7 @@ pub fn transfer(from_pubkey: &Pubkey, to_pubkey: &Pubkey, lamports: u64) -> Inst
         AccountMeta::new(*from_pubkey, true),
         AccountMeta::new(*to_pubkey, false),
     ];
-    Instruction::new_with_bincode(
+    utils::new_with_bincode(
         // Pubkey::new(*SYSTEM_PROGRAM_ID),
         Pubkey::from_str("11111111111111111111111111111111").unwrap(),
         &SystemInstruction::Transfer { lamports },
@@ -777,7 +821,7 @@ pub fn transfer_with_seed(
         AccountMeta::new_readonly(*from_base, true),
         AccountMeta::new(*to_pubkey, false),
     ];
-    Instruction::new_with_bincode(
+    utils::new_with_bincode(
         Pubkey::from_str("11111111111111111111111111111111").unwrap(),
         &SystemInstruction::TransferWithSeed {
             lamports,
@@ -974,7 +1018,7 @@ pub fn transfer_with_seed(
 /// ```
 pub fn allocate(pubkey: &Pubkey, space: u64) -> Instruction {
     let account_metas = vec![AccountMeta::new(*pubkey, true)];
-    Instruction::new_with_bincode(
+    utils::new_with_bincode(
         Pubkey::from_str("11111111111111111111111111111111").unwrap(),
         &SystemInstruction::Allocate { space },
         account_metas,
@@ -992,7 +1036,7 @@ pub fn allocate_with_seed(
         AccountMeta::new(*address, false),
         AccountMeta::new_readonly(*base, true),
     ];
-    Instruction::new_with_bincode(
+    utils::new_with_bincode(
         Pubkey::from_str("11111111111111111111111111111111").unwrap(),
         &SystemInstruction::AllocateWithSeed {
             base: *base,
@@ -1151,3 +1195,212 @@ pub fn transfer_many(from_pubkey: &Pubkey, to_lamports: &[(Pubkey, u64)]) -> Vec
         .map(|(to_pubkey, lamports)| transfer(from_pubkey, to_pubkey, *lamports))
         .collect()
 }
+
+fn recent_blockhashes_sysvar_id() -> Pubkey {
+    Pubkey::from_str(RECENT_BLOCKHASHES_SYSVAR_ID)
+        .expect("recent blockhashes sysvar id is a valid base58 pubkey")
+}
+
+fn rent_sysvar_id() -> Pubkey {
+    Pubkey::from_str(SYSVAR_ID).expect("rent sysvar id is a valid base58 pubkey")
+}
+
+/// Advance the value of a durable transaction nonce.
+///
+/// This function produces an [`Instruction`] containing a serialized
+/// [`SystemInstruction::AdvanceNonceAccount`]. It must be the first
+/// instruction of a transaction that uses the nonce as its blockhash.
+///
+/// # Required signers
+///
+/// The `authorized_pubkey` signer must sign the transaction.
+pub fn advance_nonce_account(nonce_pubkey: &Pubkey, authorized_pubkey: &Pubkey) -> Instruction {
+    let account_metas = vec![
+        AccountMeta::new(*nonce_pubkey, false),
+        AccountMeta::new_readonly(recent_blockhashes_sysvar_id(), false),
+        AccountMeta::new_readonly(*authorized_pubkey, true),
+    ];
+    utils::new_with_bincode(
+        system_program_id(),
+        &SystemInstruction::AdvanceNonceAccount,
+        account_metas,
+    )
+}
+
+/// Withdraw lamports from a durable transaction nonce account.
+///
+/// This function produces an [`Instruction`] containing a serialized
+/// [`SystemInstruction::WithdrawNonceAccount`]. The withdrawal must leave the
+/// account balance above the rent exempt reserve or at zero.
+///
+/// # Required signers
+///
+/// The `authorized_pubkey` signer must sign the transaction.
+pub fn withdraw_nonce_account(
+    nonce_pubkey: &Pubkey,
+    authorized_pubkey: &Pubkey,
+    to_pubkey: &Pubkey,
+    lamports: u64,
+) -> Instruction {
+    let account_metas = vec![
+        AccountMeta::new(*nonce_pubkey, false),
+        AccountMeta::new(*to_pubkey, false),
+        AccountMeta::new_readonly(recent_blockhashes_sysvar_id(), false),
+        AccountMeta::new_readonly(rent_sysvar_id(), false),
+        AccountMeta::new_readonly(*authorized_pubkey, true),
+    ];
+    utils::new_with_bincode(
+        system_program_id(),
+        &SystemInstruction::WithdrawNonceAccount(lamports),
+        account_metas,
+    )
+}
+
+/// Initialize an allocated account as a durable transaction nonce account.
+///
+/// This function produces an [`Instruction`] containing a serialized
+/// [`SystemInstruction::InitializeNonceAccount`], setting `authorized_pubkey`
+/// as the nonce authority. No signatures are required.
+pub fn initialize_nonce_account(nonce_pubkey: &Pubkey, authorized_pubkey: &Pubkey) -> Instruction {
+    let account_metas = vec![
+        AccountMeta::new(*nonce_pubkey, false),
+        AccountMeta::new_readonly(recent_blockhashes_sysvar_id(), false),
+        AccountMeta::new_readonly(rent_sysvar_id(), false),
+    ];
+    utils::new_with_bincode(
+        system_program_id(),
+        &SystemInstruction::InitializeNonceAccount(*authorized_pubkey),
+        account_metas,
+    )
+}
+
+/// Change the authority of a durable transaction nonce account.
+///
+/// This function produces an [`Instruction`] containing a serialized
+/// [`SystemInstruction::AuthorizeNonceAccount`].
+///
+/// # Required signers
+///
+/// The `authorized_pubkey` signer must sign the transaction.
+pub fn authorize_nonce_account(
+    nonce_pubkey: &Pubkey,
+    authorized_pubkey: &Pubkey,
+    new_authority: &Pubkey,
+) -> Instruction {
+    let account_metas = vec![
+        AccountMeta::new(*nonce_pubkey, false),
+        AccountMeta::new_readonly(*authorized_pubkey, true),
+    ];
+    utils::new_with_bincode(
+        system_program_id(),
+        &SystemInstruction::AuthorizeNonceAccount(*new_authority),
+        account_metas,
+    )
+}
+
+/// One-time idempotent upgrade of a legacy nonce account.
+///
+/// This function produces an [`Instruction`] containing a serialized
+/// [`SystemInstruction::UpgradeNonceAccount`], bumping the nonce out of the
+/// chain blockhash domain. No signatures are required.
+pub fn upgrade_nonce_account(nonce_pubkey: &Pubkey) -> Instruction {
+    let account_metas = vec![AccountMeta::new(*nonce_pubkey, false)];
+    utils::new_with_bincode(
+        system_program_id(),
+        &SystemInstruction::UpgradeNonceAccount,
+        account_metas,
+    )
+}
+
+/// Size of a durable transaction nonce account, in bytes.
+pub const NONCE_STATE_SIZE: u64 = 80;
+
+/// Create and initialize a durable transaction nonce account.
+///
+/// Returns the [`create_account`] instruction allocating [`NONCE_STATE_SIZE`]
+/// bytes owned by the system program, followed by
+/// [`initialize_nonce_account`]. `lamports` should cover the rent exempt
+/// minimum for the nonce account.
+///
+/// # Required signers
+///
+/// The `from_pubkey` and `nonce_pubkey` signers must sign the transaction.
+pub fn create_nonce_account(
+    from_pubkey: &Pubkey,
+    nonce_pubkey: &Pubkey,
+    authority: &Pubkey,
+    lamports: u64,
+) -> Vec<Instruction> {
+    vec![
+        create_account(
+            from_pubkey,
+            nonce_pubkey,
+            lamports,
+            NONCE_STATE_SIZE,
+            &system_program_id(),
+        ),
+        initialize_nonce_account(nonce_pubkey, authority),
+    ]
+}
+
+/// Create an account funded with the minimum balance for rent exemption.
+///
+/// The balance is computed from `space` and `lamports_per_byte_year` (see
+/// [`DEFAULT_LAMPORTS_PER_BYTE_YEAR`]) with the default exemption threshold,
+/// then passed to [`create_account`]. Fails with
+/// [`ProgramError::ArithmeticOverflow`] if the balance overflows.
+///
+/// # Required signers
+///
+/// The `from_pubkey` and `to_pubkey` signers must sign the transaction.
+pub fn create_account_rent_exempt(
+    from_pubkey: &Pubkey,
+    to_pubkey: &Pubkey,
+    space: u64,
+    owner: &Pubkey,
+    lamports_per_byte_year: u64,
+) -> Result<Instruction, ProgramError> {
+    let lamports = minimum_balance_for_rent_exemption_with_rate(space, lamports_per_byte_year)?;
+    Ok(create_account(
+        from_pubkey,
+        to_pubkey,
+        lamports,
+        space,
+        owner,
+    ))
+}
+
+/// Allocate space for an account and assign it to a program.
+///
+/// Returns the [`allocate`] and [`assign`] instructions, in that order.
+///
+/// # Required signers
+///
+/// The `pubkey` signer must sign the transaction.
+pub fn allocate_and_assign(pubkey: &Pubkey, space: u64, owner: &Pubkey) -> [Instruction; 2] {
+    [allocate(pubkey, space), assign(pubkey, owner)]
+}
+
+/// Transfer lamports to many recipients, attaching `memo` to each transfer.
+///
+/// Every [`transfer`] instruction is followed by a memo instruction signed by
+/// `from_pubkey`.
+///
+/// # Required signers
+///
+/// The `from_pubkey` signer must sign the transaction.
+pub fn transfer_many_with_memo(
+    from_pubkey: &Pubkey,
+    to_lamports: &[(Pubkey, u64)],
+    memo: &str,
+) -> Vec<Instruction> {
+    to_lamports
+        .iter()
+        .flat_map(|(to_pubkey, lamports)| {
+            [
+                transfer(from_pubkey, to_pubkey, *lamports),
+                build_memo(memo, &[from_pubkey]),
+            ]
+        })
+        .collect()
+}
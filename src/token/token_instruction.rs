@@ -1,3 +1,7 @@
+use crate::metaplex::extension::MINT_LEN;
+use crate::token::associated_account::create_associated_token_account_idempotent;
+use crate::token::associated_account::get_associated_token_address_with_program_id;
+use crate::token::system_instruction::create_account;
 use crate::token::system_instruction::SYSVAR_ID;
 use ic_solana::types::{AccountMeta, Instruction, Pubkey};
 use std::str::FromStr;
@@ -100,6 +104,108 @@ pub fn mint_to(
     }
 }
 
+/// Creates a `MintToChecked` instruction.
+/// Like MintTo, but also asserts the mint's decimals.
+pub fn mint_to_checked(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    account_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    let mut data: Vec<u8> = vec![];
+    data.push(14);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    }
+}
+
+/// Creates the instructions minting `amount` tokens to the associated token
+/// account of `ata_owner`, creating it first if it does not exist yet:
+/// `[create_associated_token_account_idempotent, mint_to]`.
+///
+/// `mint_to_checked` is used instead of `mint_to` when `decimals` is set. The
+/// `mint_authority` signs the transaction and also funds the account creation.
+pub fn mint_tokens_to_ata(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    mint_authority: &Pubkey,
+    ata_owner: &Pubkey,
+    amount: u64,
+    decimals: Option<u8>,
+) -> Vec<Instruction> {
+    let ata = get_associated_token_address_with_program_id(ata_owner, mint, token_program_id);
+    let mint_ix = match decimals {
+        Some(decimals) => mint_to_checked(
+            token_program_id,
+            mint,
+            &ata,
+            mint_authority,
+            &[],
+            amount,
+            decimals,
+        ),
+        None => mint_to(token_program_id, mint, &ata, mint_authority, &[], amount),
+    };
+    vec![
+        create_associated_token_account_idempotent(
+            mint_authority,
+            ata_owner,
+            mint,
+            token_program_id,
+        ),
+        mint_ix,
+    ]
+}
+
+/// Creates the instructions deploying a new mint together with the
+/// associated token account of `ata_owner`:
+/// `[create_account, initialize_mint2, create_associated_token_account_idempotent]`.
+///
+/// `lamports` must cover rent exemption for a mint account without extensions.
+/// The `payer` and `mint` signers must sign the transaction.
+#[allow(clippy::too_many_arguments)]
+pub fn create_mint_and_ata(
+    payer: &Pubkey,
+    mint: &Pubkey,
+    token_program_id: &Pubkey,
+    mint_authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
+    decimals: u8,
+    ata_owner: &Pubkey,
+    lamports: u64,
+) -> Vec<Instruction> {
+    vec![
+        create_account(payer, mint, lamports, MINT_LEN, token_program_id),
+        initialize_mint2(
+            token_program_id,
+            mint,
+            mint_authority,
+            freeze_authority,
+            decimals,
+        ),
+        create_associated_token_account_idempotent(payer, ata_owner, mint, token_program_id),
+    ]
+}
+
 pub fn initialize_mint_close_authority(
     token_mint: &Pubkey,
     close_authority: Option<&Pubkey>,
@@ -125,6 +231,113 @@ pub fn initialize_mint_close_authority(
     }
 }
 
+/// Creates a `TransferFeeExtension::InitializeTransferFeeConfig` instruction.
+/// Must be sent before the mint is initialized.
+pub fn initialize_transfer_fee_config(
+    token_mint: &Pubkey,
+    transfer_fee_config_authority: Option<&Pubkey>,
+    withdraw_withheld_authority: Option<&Pubkey>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Instruction {
+    let mut data: Vec<u8> = Vec::new();
+    data.push(26u8);
+    data.push(0u8);
+    for authority in [transfer_fee_config_authority, withdraw_withheld_authority] {
+        match authority {
+            Some(p) => {
+                data.push(1);
+                data.extend_from_slice(&p.to_bytes());
+            }
+            None => {
+                data.push(0);
+            }
+        }
+    }
+    data.extend_from_slice(&transfer_fee_basis_points.to_le_bytes());
+    data.extend_from_slice(&maximum_fee.to_le_bytes());
+
+    let accounts = vec![AccountMeta::new(*token_mint, false)];
+    Instruction {
+        program_id: token22_program_id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates a `InitializeNonTransferableMint` instruction.
+/// Must be sent before the mint is initialized.
+pub fn initialize_non_transferable_mint(token_mint: &Pubkey) -> Instruction {
+    let accounts = vec![AccountMeta::new(*token_mint, false)];
+    Instruction {
+        program_id: token22_program_id(),
+        accounts,
+        data: vec![32u8],
+    }
+}
+
+/// Creates a `InterestBearingMintExtension::Initialize` instruction, with the
+/// rate in basis points. Must be sent before the mint is initialized.
+pub fn initialize_interest_bearing_mint(
+    token_mint: &Pubkey,
+    rate_authority: Option<&Pubkey>,
+    rate: i16,
+) -> Instruction {
+    let mut data: Vec<u8> = Vec::new();
+    data.push(33u8);
+    data.push(0u8);
+    data.extend_from_slice(&rate_authority.copied().unwrap_or_default().to_bytes());
+    data.extend_from_slice(&rate.to_le_bytes());
+
+    let accounts = vec![AccountMeta::new(*token_mint, false)];
+    Instruction {
+        program_id: token22_program_id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates a `InitializePermanentDelegate` instruction.
+/// Must be sent before the mint is initialized.
+pub fn initialize_permanent_delegate(token_mint: &Pubkey, delegate: &Pubkey) -> Instruction {
+    let mut data: Vec<u8> = Vec::new();
+    data.push(35u8);
+    data.extend_from_slice(&delegate.to_bytes());
+
+    let accounts = vec![AccountMeta::new(*token_mint, false)];
+    Instruction {
+        program_id: token22_program_id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates a `TransferHookExtension::Initialize` instruction.
+/// Must be sent before the mint is initialized.
+pub fn initialize_transfer_hook(
+    token_mint: &Pubkey,
+    authority: Option<&Pubkey>,
+    transfer_hook_program_id: Option<&Pubkey>,
+) -> Instruction {
+    let mut data: Vec<u8> = Vec::new();
+    data.push(36u8);
+    data.push(0u8);
+    data.extend_from_slice(&authority.copied().unwrap_or_default().to_bytes());
+    data.extend_from_slice(
+        &transfer_hook_program_id
+            .copied()
+            .unwrap_or_default()
+            .to_bytes(),
+    );
+
+    let accounts = vec![AccountMeta::new(*token_mint, false)];
+    Instruction {
+        program_id: token22_program_id(),
+        accounts,
+        data,
+    }
+}
+
 pub fn initialize_metadata_pointer(
     token_mint: &Pubkey,
     metadata_addr: &Pubkey,
@@ -143,6 +356,49 @@ pub fn initialize_metadata_pointer(
     }
 }
 
+/// Creates a `MetadataPointer::Update` instruction.
+/// Points the mint at a new metadata account, or clears the pointer when
+/// `new_metadata_address` is `None`.
+///
+/// Accounts expected by this instruction:
+///
+///   * Single authority
+///   0. `[writable]` The mint.
+///   1. `[signer]` The metadata pointer authority.
+///
+///   * Multisignature authority
+///   0. `[writable]` The mint.
+///   1. `[]` The mint's metadata pointer authority.
+///   2. ..2+M `[signer]` M signer accounts.
+pub fn update_metadata_pointer(
+    token_mint: &Pubkey,
+    authority: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    new_metadata_address: Option<&Pubkey>,
+) -> Instruction {
+    let mut data: Vec<u8> = Vec::new();
+    data.push(39u8);
+    data.push(1u8);
+    match new_metadata_address {
+        Some(p) => data.extend_from_slice(&p.to_bytes()),
+        None => data.extend_from_slice(&[0u8; 32]),
+    }
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*token_mint, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Instruction {
+        program_id: token22_program_id(),
+        accounts,
+        data,
+    }
+}
+
 /// Creates a `CloseAccount` instruction.
 /// Close an account by transferring all its SOL to the destination account.
 /// Non-native accounts may only be closed if its token amount is zero.
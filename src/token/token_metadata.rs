@@ -1,10 +1,45 @@
+use crate::metaplex::extension::{
+    calculate_mint_account_size, token_metadata_space, ExtensionType, ACCOUNT_LEN, MINT_LEN,
+    TLV_HEADER_LEN,
+};
+use crate::metaplex::types::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
 use crate::token::program_error::ProgramError;
 use borsh_derive::{BorshDeserialize, BorshSerialize};
+use core::fmt;
 use ic_solana::types::{AccountMeta, Instruction, Pubkey};
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
 pub struct OptionalNonZeroPubkey(pub Pubkey);
 
+impl OptionalNonZeroPubkey {
+    /// `None` if the inner pubkey is all zeros, which is how an absent
+    /// pubkey is stored on chain
+    pub fn to_option(&self) -> Option<Pubkey> {
+        if self.0 == Pubkey::default() {
+            None
+        } else {
+            Some(self.0)
+        }
+    }
+}
+
+impl From<Option<Pubkey>> for OptionalNonZeroPubkey {
+    fn from(option: Option<Pubkey>) -> Self {
+        Self(option.unwrap_or_default())
+    }
+}
+
+impl From<OptionalNonZeroPubkey> for Option<Pubkey> {
+    fn from(value: OptionalNonZeroPubkey) -> Self {
+        value.to_option()
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
 pub struct TokenMetadata {
     /// The authority that can sign to update the metadata
@@ -24,12 +59,149 @@ pub struct TokenMetadata {
 }
 
 impl TokenMetadata {
+    /// Creates metadata without additional fields, checking the name, symbol
+    /// and uri against [`MAX_NAME_LENGTH`], [`MAX_SYMBOL_LENGTH`] and
+    /// [`MAX_URI_LENGTH`] (in bytes)
+    pub fn new(
+        mint: Pubkey,
+        update_authority: Option<Pubkey>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<Self, TokenMetadataError> {
+        if name.len() > MAX_NAME_LENGTH {
+            return Err(TokenMetadataError::NameTooLong(name.len()));
+        }
+        if symbol.len() > MAX_SYMBOL_LENGTH {
+            return Err(TokenMetadataError::SymbolTooLong(symbol.len()));
+        }
+        if uri.len() > MAX_URI_LENGTH {
+            return Err(TokenMetadataError::UriTooLong(uri.len()));
+        }
+        Ok(Self {
+            update_authority: update_authority.into(),
+            mint,
+            name,
+            symbol,
+            uri,
+            additional_metadata: vec![],
+        })
+    }
+
     /// Gives the total size of this struct as a TLV entry in an account
     pub fn tlv_size_of(&self) -> Result<usize, ProgramError> {
         10usize
             .checked_add(get_instance_packed_len(self).unwrap())
             .ok_or(ProgramError::InvalidAccountData)
     }
+
+    /// Value of the first additional metadata entry with the given key
+    pub fn get_additional_metadata_value(&self, key: &str) -> Option<&str> {
+        self.additional_metadata
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Updates the value of an existing key in place, or appends a new entry
+    pub fn set_additional_metadata_value(&mut self, key: String, value: String) {
+        match self.additional_metadata.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.additional_metadata.push((key, value)),
+        }
+    }
+
+    /// Checks that no additional metadata key is stored twice
+    pub fn validate(&self) -> Result<(), TokenMetadataError> {
+        let mut keys = HashSet::new();
+        for (key, _) in &self.additional_metadata {
+            if !keys.insert(key.as_str()) {
+                return Err(TokenMetadataError::DuplicateKey(key.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the entry with the given key, returning whether it existed
+    pub fn remove_additional_metadata_key(&mut self, key: &str) -> bool {
+        let len = self.additional_metadata.len();
+        self.additional_metadata.retain(|(k, _)| k != key);
+        len != self.additional_metadata.len()
+    }
+}
+
+/// Account type byte of a mint with extensions, stored right after the
+/// padding to the length of a token account
+const ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// Reads the `TokenMetadata` extension stored in the account data of a
+/// Token-2022 mint
+pub fn parse_token_metadata_from_account_data(data: &[u8]) -> Result<TokenMetadata, ProgramError> {
+    let account_type_index = ACCOUNT_LEN as usize;
+    let tlv_header_len = TLV_HEADER_LEN as usize;
+    if data.len() <= MINT_LEN as usize {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if data.get(account_type_index) != Some(&ACCOUNT_TYPE_MINT) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let token_metadata_type = u16::from(ExtensionType::TokenMetadata);
+    let mut start = account_type_index + 1;
+    while start + tlv_header_len <= data.len() {
+        let extension_type = u16::from_le_bytes([data[start], data[start + 1]]);
+        let length = u16::from_le_bytes([data[start + 2], data[start + 3]]) as usize;
+        let value_start = start + tlv_header_len;
+        let value_end = value_start + length;
+        if value_end > data.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if extension_type == token_metadata_type {
+            return borsh::from_slice(&data[value_start..value_end])
+                .map_err(|err| ProgramError::BorshIoError(err.to_string()));
+        }
+        // Uninitialized marks the end of the written extensions
+        if extension_type == u16::from(ExtensionType::Uninitialized) {
+            break;
+        }
+        start = value_end;
+    }
+    Err(ProgramError::InvalidAccountData)
+}
+
+/// Total account data size of a Token-2022 mint holding the metadata pointer
+/// and token metadata extensions, the latter with the given field lengths.
+///
+/// The mint must be funded for this size, but created with the metadata
+/// pointer only: the program reallocates the account when the metadata is
+/// initialized.
+pub fn token_metadata_account_size(
+    name_len: usize,
+    symbol_len: usize,
+    uri_len: usize,
+    additional_keys: &[(String, String)],
+) -> usize {
+    let mint_space = calculate_mint_account_size(&[ExtensionType::MetadataPointer]);
+    let metadata_space = token_metadata_space(
+        name_len,
+        symbol_len,
+        uri_len,
+        additional_keys
+            .iter()
+            .map(|(key, value)| (key.len(), value.len())),
+    );
+    (mint_space + metadata_space) as usize
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TokenMetadataError {
+    #[error("Additional metadata key is stored twice: {0}")]
+    DuplicateKey(String),
+    #[error("Name must be at most 32 bytes, found {0}")]
+    NameTooLong(usize),
+    #[error("Symbol must be at most 10 bytes, found {0}")]
+    SymbolTooLong(usize),
+    #[error("Uri must be at most 200 bytes, found {0}")]
+    UriTooLong(usize),
 }
 
 #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
@@ -78,6 +250,37 @@ pub struct UpdateField {
     /// Value to write for the field
     pub value: String,
 }
+impl UpdateField {
+    pub fn new_name(name: impl Into<String>) -> Self {
+        Self {
+            field: Field::Name,
+            value: name.into(),
+        }
+    }
+
+    pub fn new_symbol(symbol: impl Into<String>) -> Self {
+        Self {
+            field: Field::Symbol,
+            value: symbol.into(),
+        }
+    }
+
+    pub fn new_uri(uri: impl Into<String>) -> Self {
+        Self {
+            field: Field::Uri,
+            value: uri.into(),
+        }
+    }
+
+    /// Updates, or adds, the additional metadata entry `key`
+    pub fn new_key(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            field: Field::Key(key.into()),
+            value: value.into(),
+        }
+    }
+}
+
 /// Fields in the metadata account, used for updating
 #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
 pub enum Field {
@@ -91,6 +294,30 @@ pub enum Field {
     Key(String),
 }
 
+impl FromStr for Field {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "name" => Ok(Self::Name),
+            "symbol" => Ok(Self::Symbol),
+            "uri" => Ok(Self::Uri),
+            _ => Ok(Self::Key(s.to_string())),
+        }
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Name => write!(f, "Name"),
+            Self::Symbol => write!(f, "Symbol"),
+            Self::Uri => write!(f, "Uri"),
+            Self::Key(key) => write!(f, "{}", key),
+        }
+    }
+}
+
 /// Creates an `UpdateField` instruction
 pub fn update_field(
     program_id: &Pubkey,
@@ -116,6 +343,95 @@ pub fn update_field(
     }
 }
 
+/// Remove key instruction data
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct RemoveKey {
+    /// If the idempotent flag is set to true, then the instruction will not
+    /// error if the key does not exist
+    pub idempotent: bool,
+    /// Key to remove in the additional metadata portion
+    pub key: String,
+}
+
+/// Creates a `RemoveKey` instruction
+pub fn remove_key(
+    program_id: &Pubkey,
+    metadata: &Pubkey,
+    update_authority: &Pubkey,
+    key: String,
+    idempotent: bool,
+) -> Instruction {
+    let remove_key = RemoveKey { idempotent, key };
+    let mut data: Vec<u8> = vec![234, 18, 32, 56, 89, 141, 37, 181];
+    data.append(&mut borsh::to_vec(&remove_key).unwrap());
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*metadata, false),
+            AccountMeta::new_readonly(*update_authority, true),
+        ],
+        data,
+    }
+}
+
+/// Update authority instruction data
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct UpdateAuthority {
+    /// New authority for the token metadata, or unset if `None`
+    pub new_authority: OptionalNonZeroPubkey,
+}
+
+/// Creates an `UpdateAuthority` instruction
+///
+/// Passing `None` as `new_authority` removes the update authority, making the
+/// metadata permanently immutable.
+pub fn update_authority(
+    program_id: &Pubkey,
+    metadata: &Pubkey,
+    current_authority: &Pubkey,
+    new_authority: Option<Pubkey>,
+) -> Instruction {
+    let update_authority = UpdateAuthority {
+        new_authority: new_authority.into(),
+    };
+    let mut data: Vec<u8> = vec![215, 228, 166, 228, 84, 100, 86, 123];
+    data.append(&mut borsh::to_vec(&update_authority).unwrap());
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*metadata, false),
+            AccountMeta::new_readonly(*current_authority, true),
+        ],
+        data,
+    }
+}
+
+/// Emit instruction data
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct Emit {
+    /// Start of range of data to emit
+    pub start: Option<u64>,
+    /// End of range of data to emit
+    pub end: Option<u64>,
+}
+
+/// Creates an `Emit` instruction
+pub fn emit(
+    program_id: &Pubkey,
+    metadata: &Pubkey,
+    start: Option<u64>,
+    end: Option<u64>,
+) -> Instruction {
+    let emit = Emit { start, end };
+    let mut data: Vec<u8> = vec![250, 166, 180, 250, 13, 12, 184, 70];
+    data.append(&mut borsh::to_vec(&emit).unwrap());
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![AccountMeta::new_readonly(*metadata, false)],
+        data,
+    }
+}
+
 macro_rules! impl_get_instance_packed_len {
     ($borsh:ident, $borsh_io:ident $(,#[$meta:meta])?) => {
         /// Helper struct which to count how much data would be written during serialization
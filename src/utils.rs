@@ -1,4 +1,9 @@
+use crate::compute_budget::compute_budget::{compute_budget_instructions, Priority};
+use crate::memo::build_memo;
+use crate::token::system_instruction::transfer;
+use crate::token::token_instruction::mint_to;
 use borsh::BorshSerialize;
+use curve25519_dalek::edwards::CompressedEdwardsY;
 use ic_solana::types::{AccountMeta, Instruction, Pubkey};
 pub fn new_with_borsh<T: BorshSerialize>(
     program_id: Pubkey,
@@ -12,3 +17,118 @@ pub fn new_with_borsh<T: BorshSerialize>(
         data,
     }
 }
+
+pub fn new_with_bincode<T: serde::Serialize>(
+    program_id: Pubkey,
+    data: &T,
+    accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let data = bincode::serialize(data).unwrap();
+    Instruction {
+        program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn new_with_raw_bytes(
+    program_id: Pubkey,
+    data: Vec<u8>,
+    accounts: Vec<AccountMeta>,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts,
+        data,
+    }
+}
+
+/// Whether the pubkey is a valid ed25519 point, i.e. not a program derived address.
+///
+/// Wallets and other signers are on the curve, PDAs never are.
+pub fn is_on_curve(pubkey: &Pubkey) -> bool {
+    CompressedEdwardsY(pubkey.to_bytes()).decompress().is_some()
+}
+
+/// Collects the instructions of a transaction, keeping compute budget
+/// instructions ahead of the operations regardless of the order they are added.
+#[derive(Default)]
+pub struct MultiInstructionBuilder {
+    compute_budget: Vec<Instruction>,
+    instructions: Vec<Instruction>,
+}
+
+impl MultiInstructionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn add(&mut self, instruction: Instruction) -> &mut Self {
+        self.instructions.push(instruction);
+        self
+    }
+    /// Sets the compute unit limit and price, replacing any previous setting
+    #[inline(always)]
+    pub fn add_compute_budget(&mut self, cu_limit: u32, priority: Priority) -> &mut Self {
+        self.compute_budget = compute_budget_instructions(cu_limit, priority).to_vec();
+        self
+    }
+    /// Adds an unsigned memo
+    #[inline(always)]
+    pub fn add_memo(&mut self, text: &str) -> &mut Self {
+        self.instructions.push(build_memo(text, &[]));
+        self
+    }
+    /// Adds a system program lamports transfer
+    #[inline(always)]
+    pub fn add_transfer(&mut self, from: &Pubkey, to: &Pubkey, lamports: u64) -> &mut Self {
+        self.instructions.push(transfer(from, to, lamports));
+        self
+    }
+    /// Adds a `MintTo` signed by a single mint authority
+    #[inline(always)]
+    pub fn add_mint_to(
+        &mut self,
+        token_program_id: &Pubkey,
+        mint: &Pubkey,
+        account: &Pubkey,
+        mint_authority: &Pubkey,
+        amount: u64,
+    ) -> &mut Self {
+        self.instructions.push(mint_to(
+            token_program_id,
+            mint,
+            account,
+            mint_authority,
+            &[],
+            amount,
+        ));
+        self
+    }
+    pub fn build(&self) -> Vec<Instruction> {
+        self.compute_budget
+            .iter()
+            .chain(self.instructions.iter())
+            .cloned()
+            .collect()
+    }
+}
+
+/// Merges account metas with the same pubkey, keeping the first occurrence's
+/// position and the most permissive signer and writable flags.
+pub fn deduplicate_account_metas(metas: Vec<AccountMeta>) -> Vec<AccountMeta> {
+    let mut deduped: Vec<AccountMeta> = Vec::with_capacity(metas.len());
+    for meta in metas {
+        match deduped
+            .iter_mut()
+            .find(|existing| existing.pubkey == meta.pubkey)
+        {
+            Some(existing) => {
+                existing.is_signer |= meta.is_signer;
+                existing.is_writable |= meta.is_writable;
+            }
+            None => deduped.push(meta),
+        }
+    }
+    deduped
+}